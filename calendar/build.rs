@@ -0,0 +1,16 @@
+// generates the C header for the functions in src/ffi.rs with the ffi-header feature,
+// tests/header.rs compares it with the committed include/calendar.h
+fn main() {
+    #[cfg(feature = "ffi-header")]
+    {
+        let crate_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+        let out_dir = std::env::var("OUT_DIR").unwrap();
+
+        cbindgen::generate(crate_dir)
+            .expect("Error: Could not generate the C header.")
+            .write_to_file(format!("{out_dir}/calendar.h"));
+    }
+
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+}
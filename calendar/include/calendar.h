@@ -0,0 +1,46 @@
+#ifndef CALENDAR_H
+#define CALENDAR_H
+
+/* Generated by cbindgen from src/ffi.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum AocStatus {
+  AOC_STATUS_OK = 0,
+  AOC_STATUS_NULL_POINTER = 1,
+  AOC_STATUS_INVALID_UTF8 = 2,
+  AOC_STATUS_UNKNOWN_PUZZLE = 3,
+  AOC_STATUS_PANICKED = 4,
+} AocStatus;
+
+/**
+ * Solves `part` of `day` for the `len` bytes of puzzle input at `input`.
+ *
+ * On `Ok` the answer is written to `result`, on `Panicked` the panic message is.
+ * Both are owned by the caller and have to be released with `aoc_free`.
+ * For any other status `result` is set to null.
+ *
+ * # Safety
+ *
+ * `input` has to point to `len` readable bytes, it may only be null when `len` is 0.
+ * `result` has to be a writable pointer.
+ */
+enum AocStatus aoc_solve(const uint8_t *input,
+                         uintptr_t len,
+                         uint32_t day,
+                         uint32_t part,
+                         char **result);
+
+/**
+ * Releases a string returned by `aoc_solve`, null is ignored.
+ *
+ * # Safety
+ *
+ * `result` has to come from `aoc_solve` and must not be used afterwards.
+ */
+void aoc_free(char *result);
+
+#endif /* CALENDAR_H */
@@ -0,0 +1,135 @@
+use std::{any::Any, fmt, panic, time::{Duration, Instant}};
+use rayon::{iter::{IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator, ParallelIterator}, ThreadPoolBuilder};
+
+use crate::{read_input, run, Answer, DAYS};
+
+#[derive(Debug)]
+pub enum Outcome {
+    Solved(Answer),
+    Panicked(String)
+}
+use Outcome::*;
+
+#[derive(Debug)]
+pub struct Day {
+    pub day: usize,
+    // None if the day has no input
+    pub parts: Option<Vec<Outcome>>
+}
+
+#[derive(Debug)]
+pub struct Summary {
+    pub days: Vec<Day>,
+    pub time: Duration
+}
+
+pub(crate) fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown panic")
+    }
+}
+
+// runs every part of every day with an input on a rayon thread pool
+// the parts share the cpu, so their timings are only a rough guide next to a single run
+// the allocation counters are global, with alloc-stats the pool only has one thread
+pub fn run_all() -> Summary {
+    let threads = if cfg!(feature = "alloc-stats") { 1 } else { 0 };
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .expect("Error: Could not start the thread pool.");
+
+    let start = Instant::now();
+
+    let days = pool.install(|| {
+        DAYS
+            .par_iter()
+            .enumerate()
+            .map(|(i, parts)| {
+                let day = i + 1;
+
+                let parts = read_input(day).ok().map(|input| {
+                    (1..=parts.len())
+                        .into_par_iter()
+                        .map(|part| match panic::catch_unwind(|| run(day, part, &input).unwrap()) {
+                            Ok(answer) => Solved(answer),
+                            Err(payload) => Panicked(panic_message(payload))
+                        })
+                        .collect()
+                });
+
+                Day { day, parts }
+            })
+            .collect()
+    });
+
+    Summary {
+        days,
+        time: start.elapsed()
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut solved = 0;
+        let mut panicked = 0;
+        let mut missing = 0;
+        let mut solver_time = Duration::ZERO;
+
+        for day in self.days.iter() {
+            write!(f, "Day {:02}", day.day)?;
+
+            match &day.parts {
+                Some(parts) => for (i, outcome) in parts.iter().enumerate() {
+                    match outcome {
+                        Solved(answer) => {
+                            write!(f, " | part {}: {} ({:?})", i + 1, answer.answer, answer.time)?;
+                            #[cfg(feature = "alloc-stats")]
+                            write!(f, " [{}]", answer.stats)?;
+                            solver_time += answer.time;
+                            solved += 1;
+                        },
+                        Panicked(message) => {
+                            write!(f, " | part {}: panicked: {}", i + 1, message)?;
+                            panicked += 1;
+                        }
+                    }
+                },
+                None => {
+                    write!(f, " | no input")?;
+                    missing += 1;
+                }
+            }
+
+            writeln!(f)?;
+        }
+
+        write!(
+            f,
+            "Solved {} parts in {:?} ({:?} solver time), {} panicked, {} days without input",
+            solved,
+            self.time,
+            solver_time,
+            panicked,
+            missing
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn panics() {
+        let payload = panic::catch_unwind(|| panic!("Error: Invalid character '{}'.", '#')).unwrap_err();
+        assert_eq!(panic_message(payload), "Error: Invalid character '#'.");
+
+        let payload = panic::catch_unwind(|| panic!("Wrong color.")).unwrap_err();
+        assert_eq!(panic_message(payload), "Wrong color.");
+    }
+}
@@ -0,0 +1,110 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt,
+    sync::atomic::{AtomicUsize, Ordering::Relaxed}
+};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+// system allocator that keeps count of what goes through it
+pub struct Counting;
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn grow(size: usize) {
+    BYTES.fetch_add(size, Relaxed);
+    let current = CURRENT.fetch_add(size, Relaxed) + size;
+    PEAK.fetch_max(current, Relaxed);
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Relaxed);
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Relaxed);
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            // a realloc counts as an allocation, only growth adds to the allocated bytes
+            ALLOCATIONS.fetch_add(1, Relaxed);
+            if new_size > layout.size() {
+                grow(new_size - layout.size());
+            } else {
+                CURRENT.fetch_sub(layout.size() - new_size, Relaxed);
+            }
+        }
+        new_ptr
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub allocations: usize,
+    pub bytes: usize,
+    pub peak: usize
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} allocations, {} bytes, {} bytes peak", self.allocations, self.bytes, self.peak)
+    }
+}
+
+// counts the allocations made while running f, the peak is relative to the heap in use before
+// the counters are global, so anything allocating on another thread at the same time is counted too
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Stats) {
+    let allocations = ALLOCATIONS.load(Relaxed);
+    let bytes = BYTES.load(Relaxed);
+    let current = CURRENT.load(Relaxed);
+    PEAK.store(current, Relaxed);
+
+    let result = f();
+
+    let stats = Stats {
+        allocations: ALLOCATIONS.load(Relaxed) - allocations,
+        bytes: BYTES.load(Relaxed) - bytes,
+        peak: PEAK.load(Relaxed).saturating_sub(current)
+    };
+
+    (result, stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts() {
+        let (_, stats) = measure(|| {
+            let mut v: Vec<u64> = Vec::with_capacity(16);
+            v.extend(0..16);
+            v
+        });
+        assert!(stats.allocations >= 1);
+        assert!(stats.bytes >= 128);
+        assert!(stats.peak >= 128);
+    }
+}
@@ -0,0 +1,34 @@
+use std::{env, error::Error, fs, io::{self, BufRead, Write}};
+use calendar::{input_path, repl::Session};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = env::args().skip(1);
+    let day: usize = args.next().ok_or("Usage: repl <day> [input]")?.parse()?;
+    let path = args.next().map_or(input_path(day), Into::into);
+
+    let mut session = Session::new(day, fs::read_to_string(&path)?)?;
+    println!("Loaded day {day:02} from {}, type help for the commands.", path.display());
+
+    let mut stdout = io::stdout();
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        print!("> ");
+        stdout.flush()?;
+
+        let Some(line) = lines.next() else {
+            break;
+        };
+        let line = line?;
+
+        if matches!(line.trim(), "quit" | "exit") {
+            break;
+        }
+
+        let output = session.execute(&line);
+        if !output.is_empty() {
+            println!("{output}");
+        }
+    }
+
+    Ok(())
+}
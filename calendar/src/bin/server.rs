@@ -0,0 +1,13 @@
+use std::{env, error::Error};
+use calendar::server::serve;
+use tiny_http::Server;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let address = env::args().nth(1).unwrap_or(String::from("127.0.0.1:8080"));
+    let server = Server::http(&address).map_err(|error| error.to_string())?;
+
+    println!("Listening on http://{address}, POST puzzle input to /day/{{n}}/part/{{p}}");
+    serve(&server);
+
+    Ok(())
+}
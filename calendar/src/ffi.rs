@@ -0,0 +1,62 @@
+use std::{ffi::{c_char, CString}, panic, ptr, slice, str};
+
+use crate::{all::panic_message, run, solver};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AocStatus {
+    Ok = 0,
+    NullPointer = 1,
+    InvalidUtf8 = 2,
+    UnknownPuzzle = 3,
+    Panicked = 4
+}
+
+/// Solves `part` of `day` for the `len` bytes of puzzle input at `input`.
+///
+/// On `Ok` the answer is written to `result`, on `Panicked` the panic message is.
+/// Both are owned by the caller and have to be released with `aoc_free`.
+/// For any other status `result` is set to null.
+///
+/// # Safety
+///
+/// `input` has to point to `len` readable bytes, it may only be null when `len` is 0.
+/// `result` has to be a writable pointer.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(input: *const u8, len: usize, day: u32, part: u32, result: *mut *mut c_char) -> AocStatus {
+    if (input.is_null() && len > 0) || result.is_null() {
+        return AocStatus::NullPointer;
+    }
+    *result = ptr::null_mut();
+
+    let input = if len == 0 { &[] } else { slice::from_raw_parts(input, len) };
+    let Ok(input) = str::from_utf8(input) else {
+        return AocStatus::InvalidUtf8;
+    };
+
+    let (day, part) = (day as usize, part as usize);
+    if solver(day, part).is_none() {
+        return AocStatus::UnknownPuzzle;
+    }
+
+    // panics must not unwind into the caller
+    let (status, message) = match panic::catch_unwind(|| run(day, part, input).unwrap()) {
+        Ok(answer) => (AocStatus::Ok, answer.answer),
+        Err(payload) => (AocStatus::Panicked, panic_message(payload))
+    };
+
+    *result = CString::new(message.replace('\0', "")).unwrap().into_raw();
+    status
+}
+
+/// Releases a string returned by `aoc_solve`, null is ignored.
+///
+/// # Safety
+///
+/// `result` has to come from `aoc_solve` and must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_free(result: *mut c_char) {
+    if !result.is_null() {
+        drop(CString::from_raw(result));
+    }
+}
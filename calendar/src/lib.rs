@@ -0,0 +1,130 @@
+use std::{fmt, fs, io, path::PathBuf, time::{Duration, Instant}};
+
+pub mod all;
+pub mod ffi;
+pub mod repl;
+
+#[cfg(feature = "alloc-stats")]
+pub mod alloc;
+
+#[cfg(feature = "server")]
+pub mod server;
+
+pub type Solver = fn(&str) -> String;
+
+// the solvers of each day with the puzzle parameters filled in
+pub const DAYS: [&[Solver]; 25] = [
+    &[|input| day01::part1(input).to_string(), |input| day01::part2(input).to_string()],
+    &[|input| day02::part1(input).to_string(), |input| day02::part2(input).to_string()],
+    &[|input| day03::part1(input).to_string(), |input| day03::part2(input).to_string()],
+    &[|input| day04::part1(input).to_string(), |input| day04::part2(input).to_string()],
+    &[|input| day05::part1(input).to_string(), |input| day05::part2(input).to_string()],
+    &[|input| day06::part1(input).to_string(), |input| day06::part2(input).to_string()],
+    &[|input| day07::both(input, &day07::Ruleset::standard()).to_string(), |input| day07::both(input, &day07::Ruleset::jokers()).to_string()],
+    &[|input| day08::part1(input).to_string(), |input| day08::part2(input).to_string()],
+    &[|input| day09::both(input, false).to_string(), |input| day09::both(input, true).to_string()],
+    &[|input| day10::part1(input).to_string(), |input| day10::part2(input).to_string()],
+    &[|input| day11::solve(input, 2).to_string(), |input| day11::solve(input, 1_000_000).to_string()],
+    &[|input| day12::part1(input).to_string(), |input| day12::part2(input).to_string()],
+    &[|input| day13::part1(input).to_string(), |input| day13::part2(input).to_string()],
+    &[|input| day14::part1(input).to_string(), |input| day14::part2(input).to_string()],
+    &[|input| day15::part1(input).to_string(), |input| day15::part2(input).to_string()],
+    &[|input| day16::part1(input).to_string(), |input| day16::part2(input).to_string()],
+    &[|input| day17::part1(input).to_string(), |input| day17::part2(input).to_string()],
+    &[|input| day18::part1(input).to_string(), |input| day18::part2(input).to_string()],
+    &[|input| day19::part1(input).to_string(), |input| day19::part2(input).to_string()],
+    &[|input| day20::part1(input).to_string(), |input| day20::part2(input).to_string()],
+    &[|input| day21::part1(input, 64).to_string(), |input| day21::part2(input, 26501365).to_string()],
+    &[|input| day22::part1(input).to_string(), |input| day22::part2(input).to_string()],
+    &[|input| day23::part1(input).to_string(), |input| day23::part2(input).to_string()],
+    &[
+        |input| day24::part1(input, (200000000000000_i128, 400000000000000_i128)).to_string(),
+        |input| day24::part2(input).to_string()
+    ],
+    &[|input| day25::part1(input).to_string()],
+];
+
+pub fn solver(day: usize, part: usize) -> Option<Solver> {
+    DAYS
+        .get(day.checked_sub(1)?)?
+        .get(part.checked_sub(1)?)
+        .copied()
+}
+
+pub fn input_path(day: usize) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{day:02}"))
+        .join("input.txt")
+}
+
+pub fn read_input(day: usize) -> io::Result<String> {
+    fs::read_to_string(input_path(day))
+}
+
+#[derive(Debug)]
+pub struct Answer {
+    pub day: usize,
+    pub part: usize,
+    pub answer: String,
+    pub time: Duration,
+    #[cfg(feature = "alloc-stats")]
+    pub stats: alloc::Stats
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {:02} part {}: {} ({:?})", self.day, self.part, self.answer, self.time)?;
+
+        #[cfg(feature = "alloc-stats")]
+        write!(f, " [{}]", self.stats)?;
+
+        Ok(())
+    }
+}
+
+pub fn run(day: usize, part: usize, input: &str) -> Option<Answer> {
+    let solver = solver(day, part)?;
+
+    #[cfg(feature = "alloc-stats")]
+    let ((answer, time), stats) = alloc::measure(|| {
+        let start = Instant::now();
+        let answer = solver(input);
+        (answer, start.elapsed())
+    });
+
+    #[cfg(not(feature = "alloc-stats"))]
+    let (answer, time) = {
+        let start = Instant::now();
+        let answer = solver(input);
+        (answer, start.elapsed())
+    };
+
+    Some(Answer {
+        day,
+        part,
+        answer,
+        time,
+        #[cfg(feature = "alloc-stats")]
+        stats
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solvers() {
+        let input = "1abc2
+            pqr3stu8vwx
+            a1b2c3d4e5f
+            treb7uchet";
+        assert_eq!(solver(1, 1).unwrap()(input), "142");
+        assert_eq!(run(1, 1, input).unwrap().answer, "142");
+
+        assert!(solver(25, 2).is_none());
+        assert!(solver(0, 1).is_none());
+        assert!(solver(26, 1).is_none());
+    }
+}
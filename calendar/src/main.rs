@@ -0,0 +1,41 @@
+use std::{env, error::Error, panic};
+use calendar::{all::run_all, read_input, run, DAYS};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    // run everything concurrently, panics are reported in the summary instead
+    if args.iter().any(|arg| arg == "--all") {
+        panic::set_hook(Box::new(|_| {}));
+        println!("{}", run_all());
+        return Ok(());
+    }
+
+    let mut days: Vec<usize> = args
+        .iter()
+        .map(|day| day.parse())
+        .collect::<Result<_, _>>()?;
+
+    if days.is_empty() {
+        days = (1..=DAYS.len()).collect();
+    }
+
+    for day in days {
+        let input = match read_input(day) {
+            Ok(input) => input,
+            Err(error) => {
+                println!("Day {day:02}: no input ({error})");
+                continue;
+            }
+        };
+
+        for part in 1.. {
+            match run(day, part, &input) {
+                Some(answer) => println!("{answer}"),
+                None => break
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,288 @@
+use std::{collections::{HashMap, HashSet}, panic};
+
+use crate::{all::panic_message, run, solver};
+
+// parsed puzzle state of the days with extra commands
+enum State {
+    Almanac(day05::Almanac),
+    Workflows(HashMap<String, day19::Workflow>),
+    Network {
+        modules: HashMap<String, day20::Module>,
+        presses: usize,
+        pulses: [usize; 2]
+    },
+    Bricks {
+        bricks: Vec<day22::Brick>,
+        supports: Vec<HashSet<usize>>,
+        supported_by: Vec<HashSet<usize>>
+    },
+    Input
+}
+use State::*;
+
+fn parse(day: usize, input: &str) -> State {
+    match day {
+        5 => Almanac(day05::Almanac::from(input)),
+        19 => Workflows(
+            day19::read_list(input).0
+                .into_iter()
+                .map(|workflow| (workflow.name().to_string(), workflow))
+                .collect()
+        ),
+        20 => Network {
+            modules: day20::init_network(input),
+            presses: 0,
+            pulses: [0; 2]
+        },
+        22 => {
+            let (bricks, supports, supported_by) = day22::settle(input);
+            Bricks { bricks, supports, supported_by }
+        },
+        _ => Input
+    }
+}
+
+fn brick_list(ids: &HashSet<usize>) -> String {
+    let mut ids: Vec<usize> = ids.iter().copied().collect();
+    ids.sort();
+
+    match ids[..] {
+        [] => String::from("nothing"),
+        [0] => String::from("the ground"),
+        _ => ids.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(", ")
+    }
+}
+
+pub struct Session {
+    day: usize,
+    input: String,
+    state: State
+}
+
+impl Session {
+    // loads the input of a day once, fails with the panic message if it cannot be parsed
+    pub fn new(day: usize, input: String) -> Result<Self, String> {
+        if solver(day, 1).is_none() {
+            return Err(format!("No solver for day {day}."));
+        }
+
+        let state = panic::catch_unwind(|| parse(day, &input)).map_err(panic_message)?;
+
+        Ok(Session {
+            day,
+            input,
+            state
+        })
+    }
+
+    pub fn help(&self) -> String {
+        let mut help = vec!["part1, part2          run a part on the loaded input"];
+
+        match self.state {
+            Almanac(_) => help.extend([
+                "seeds                 list the seeds",
+                "map <category> <n>    follow a value through the maps to its location",
+                "convert <from> <to> <n>  convert a value between any two categories"
+            ]),
+            Workflows(_) => help.extend(["workflows             list the workflow names", "show workflow <name>  print a workflow"]),
+            Network { .. } => help.extend([
+                "press <n>             press the button n more times",
+                "show module <name>    print a module and its state",
+                "reset                 reload the modules"
+            ]),
+            Bricks { .. } => help.extend(["supports <id>         list the bricks a settled brick supports and rests on"]),
+            Input => ()
+        }
+
+        help.push("quit                  leave");
+        help.join("\n")
+    }
+
+    pub fn execute(&mut self, line: &str) -> String {
+        let words: Vec<&str> = line.split_whitespace().collect();
+
+        match (&mut self.state, &words[..]) {
+            (_, ["help"]) => self.help(),
+
+            (_, [part]) if part.starts_with("part") => {
+                let (day, input) = (self.day, &self.input);
+                match part[4..].parse().ok().filter(|part| solver(day, *part).is_some()) {
+                    Some(part) => match panic::catch_unwind(|| run(day, part, input).unwrap()) {
+                        Ok(answer) => format!("{} ({:?})", answer.answer, answer.time),
+                        Err(payload) => format!("Panicked: {}", panic_message(payload))
+                    },
+                    None => format!("No {part} for day {day}.")
+                }
+            },
+
+            (Almanac(almanac), ["seeds"]) => almanac.seeds
+                .iter()
+                .map(|seed| seed.to_string())
+                .collect::<Vec<_>>()
+                .join(" "),
+
+            (Almanac(almanac), ["map", category, value]) => match (value.parse::<i64>(), almanac.path(category, "location")) {
+                (Ok(value), Some(maps)) => {
+                    let mut path = vec![value.to_string()];
+                    let mut value = value;
+                    for map in maps {
+                        value = map.apply(value);
+                        path.push(value.to_string());
+                    }
+                    format!("{category} {}", path.join(" -> "))
+                },
+                (Err(_), _) => format!("Not a number: {value}."),
+                (_, None) => format!("No maps from {category} to location.")
+            },
+
+            (Almanac(almanac), ["convert", from, to, value]) => match (value.parse::<i64>(), almanac.convert(from, to)) {
+                (Ok(value), Some(map)) => format!("{from} {value} is {to} {}", map.apply(value)),
+                (Err(_), _) => format!("Not a number: {value}."),
+                (_, None) => format!("No maps from {from} to {to}.")
+            },
+
+            (Workflows(workflows), ["workflows"]) => {
+                let mut names: Vec<&String> = workflows.keys().collect();
+                names.sort();
+                names.iter().map(|name| name.as_str()).collect::<Vec<_>>().join(" ")
+            },
+
+            (Workflows(workflows), ["show", "workflow", name]) => match workflows.get(*name) {
+                Some(workflow) => workflow.to_string(),
+                None => format!("No workflow named {name}.")
+            },
+
+            (Network { modules, presses, pulses }, ["press", n]) => match n.parse::<usize>() {
+                Ok(n) => {
+                    for _ in 0..n {
+                        let sent = day20::press(modules);
+                        pulses[0] += sent[0];
+                        pulses[1] += sent[1];
+                    }
+                    *presses += n;
+                    format!("{} presses: {} high and {} low pulses", presses, pulses[0], pulses[1])
+                },
+                Err(_) => format!("Not a number: {n}.")
+            },
+
+            (Network { modules, .. }, ["show", "module", name]) => match modules.get(*name) {
+                Some(module) => module.to_string(),
+                None => format!("No module named {name}.")
+            },
+
+            (Network { .. }, ["reset"]) => {
+                self.state = parse(self.day, &self.input);
+                String::from("Modules reset.")
+            },
+
+            (Bricks { bricks, supports, supported_by }, ["supports", id]) => match id.parse::<usize>() {
+                Ok(id) if 0 < id && id <= bricks.len() => {
+                    let brick = &bricks[id - 1];
+                    format!(
+                        "Brick {} at {:?}~{:?} supports {} and rests on {}",
+                        brick.id,
+                        brick.end_a,
+                        brick.end_b,
+                        brick_list(&supports[id]),
+                        brick_list(&supported_by[id])
+                    )
+                },
+                _ => format!("No brick {id}, the ids go from 1 to {}.", bricks.len())
+            },
+
+            (_, []) => String::new(),
+            _ => format!("Unknown command: {line}. Type help for the commands.")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn almanac() {
+        let input = "seeds: 79 14 55 13
+        seed-to-soil map:
+        50 98 2
+        52 50 48
+        soil-to-fertilizer map:
+        0 15 37
+        37 52 2
+        39 0 15
+        fertilizer-to-water map:
+        49 53 8
+        0 11 42
+        42 0 7
+        57 7 4
+        water-to-light map:
+        88 18 7
+        18 25 70
+        light-to-temperature map:
+        45 77 23
+        81 45 19
+        68 64 13
+        temperature-to-humidity map:
+        0 69 1
+        1 0 69
+        humidity-to-location map:
+        60 56 37
+        56 93 4";
+        let mut session = Session::new(5, input.to_string()).unwrap();
+        assert_eq!(session.execute("seeds"), "79 14 55 13");
+        assert_eq!(session.execute("map seed 79"), "seed 79 -> 81 -> 81 -> 81 -> 74 -> 78 -> 78 -> 82");
+        assert_eq!(session.execute("map light 74"), "light 74 -> 78 -> 78 -> 82");
+        assert_eq!(session.execute("convert soil humidity 81"), "soil 81 is humidity 78");
+        assert_eq!(session.execute("convert location seed 1"), "No maps from location to seed.");
+        assert!(session.execute("part2").starts_with("46 "));
+        assert_eq!(session.execute("part3"), "No part3 for day 5.");
+
+        // almanacs that never reach location still load
+        let mut session = Session::new(5, String::from("seeds: 1\nseed-to-soil map:\n50 0 2")).unwrap();
+        assert_eq!(session.execute("convert seed soil 1"), "seed 1 is soil 51");
+        assert_eq!(session.execute("map seed 1"), "No maps from seed to location.");
+    }
+
+    #[test]
+    fn workflows() {
+        let input = "px{a<2006:qkq,m>2090:A,rfg}
+        in{s<1351:px,qqz}
+
+        {x=787,m=2655,a=1222,s=2876}";
+        let mut session = Session::new(19, input.to_string()).unwrap();
+        assert_eq!(session.execute("workflows"), "in px");
+        assert_eq!(session.execute("show workflow px"), "px{a<2006:qkq,m>2090:A,rfg}");
+        assert_eq!(session.execute("show workflow qs"), "No workflow named qs.");
+    }
+
+    #[test]
+    fn network() {
+        let input = "broadcaster -> a
+        %a -> inv, con
+        &inv -> b
+        %b -> con
+        &con -> output";
+        let mut session = Session::new(20, input.to_string()).unwrap();
+        assert_eq!(session.execute("press 1"), "1 presses: 4 high and 4 low pulses");
+        assert_eq!(session.execute("show module a"), "%a -> inv, con [on]");
+        assert_eq!(session.execute("press 999"), "1000 presses: 2750 high and 4250 low pulses");
+        assert_eq!(session.execute("reset"), "Modules reset.");
+        assert_eq!(session.execute("show module con"), "&con -> output [a: low, b: low]");
+    }
+
+    #[test]
+    fn bricks() {
+        let input = "1,0,1~1,2,1
+        0,0,2~2,0,2
+        0,2,3~2,2,3
+        0,0,4~0,2,4
+        2,0,5~2,2,5
+        0,1,6~2,1,6
+        1,1,8~1,1,9";
+        let mut session = Session::new(22, input.to_string()).unwrap();
+        assert_eq!(session.execute("supports 1"), "Brick 1 at (1, 0, 1)~(1, 2, 1) supports 2, 3 and rests on the ground");
+        assert_eq!(session.execute("supports 7"), "Brick 7 at (1, 1, 5)~(1, 1, 6) supports nothing and rests on 6");
+        assert_eq!(session.execute("supports 8"), "No brick 8, the ids go from 1 to 7.");
+        assert!(session.execute("fly").starts_with("Unknown command"));
+    }
+}
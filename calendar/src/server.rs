@@ -0,0 +1,146 @@
+use std::panic;
+use serde::{Deserialize, Serialize};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::{all::panic_message, run, solver};
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Reply {
+    pub day: Option<usize>,
+    pub part: Option<usize>,
+    pub answer: Option<String>,
+    // seconds spent in the solver
+    pub time: Option<f64>,
+    pub error: Option<String>
+}
+
+impl Reply {
+    fn error(error: &str) -> Self {
+        Reply {
+            error: Some(error.to_string()),
+            ..Default::default()
+        }
+    }
+}
+
+// expects /day/{n}/part/{p}
+fn route(url: &str) -> Option<(usize, usize)> {
+    let split: Vec<&str> = url.trim_matches('/').split('/').collect();
+
+    match split[..] {
+        ["day", day, "part", part] => Some((day.parse().ok()?, part.parse().ok()?)),
+        _ => None
+    }
+}
+
+pub fn handle(method: &Method, url: &str, body: &str) -> (u16, Reply) {
+    let Some((day, part)) = route(url) else {
+        return (404, Reply::error("Not found, use POST /day/{n}/part/{p}."));
+    };
+
+    if *method != Method::Post {
+        return (405, Reply::error("Method not allowed, use POST."));
+    }
+
+    if solver(day, part).is_none() {
+        return (404, Reply::error(&format!("No solver for day {day} part {part}.")));
+    }
+
+    // the solvers panic on input they cannot parse
+    match panic::catch_unwind(|| run(day, part, body).unwrap()) {
+        Ok(answer) => (200, Reply {
+            day: Some(day),
+            part: Some(part),
+            answer: Some(answer.answer),
+            time: Some(answer.time.as_secs_f64()),
+            error: None
+        }),
+        Err(payload) => (422, Reply {
+            day: Some(day),
+            part: Some(part),
+            error: Some(panic_message(payload)),
+            ..Default::default()
+        })
+    }
+}
+
+fn respond(mut request: Request) {
+    let mut body = String::new();
+
+    let (status, reply) = match request.as_reader().read_to_string(&mut body) {
+        Ok(_) => handle(request.method(), request.url(), &body),
+        Err(error) => (400, Reply::error(&error.to_string()))
+    };
+
+    let response = Response::from_string(serde_json::to_string(&reply).unwrap())
+        .with_status_code(status)
+        .with_header(Header::from_bytes("Content-Type", "application/json").unwrap());
+
+    if let Err(error) = request.respond(response) {
+        eprintln!("Error: Could not respond ({error}).");
+    }
+}
+
+// handles requests one at a time until the server is dropped
+pub fn serve(server: &Server) {
+    for request in server.incoming_requests() {
+        respond(request);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{io::{Read, Write}, net::TcpStream, sync::Arc, thread};
+
+    fn post(address: &str, path: &str, body: &str) -> (String, Reply) {
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(
+            stream,
+            "POST {path} HTTP/1.1\r\nHost: {address}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        ).unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        let (head, json) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.lines().next().unwrap().to_string();
+        (status, serde_json::from_str(json).unwrap())
+    }
+
+    #[test]
+    fn routes() {
+        assert_eq!(route("/day/1/part/2"), Some((1, 2)));
+        assert_eq!(route("/day/one/part/2"), None);
+        assert_eq!(route("/day/1"), None);
+
+        assert_eq!(handle(&Method::Get, "/day/1/part/1", "").0, 405);
+        assert_eq!(handle(&Method::Post, "/day/25/part/2", "").0, 404);
+        assert_eq!(handle(&Method::Post, "/day/2/part/1", "Game 1: three blue").0, 422);
+    }
+
+    #[test]
+    fn client() {
+        let server = Arc::new(Server::http("127.0.0.1:0").unwrap());
+        let address = server.server_addr().to_ip().unwrap().to_string();
+
+        let handle = thread::spawn({
+            let server = server.clone();
+            move || serve(&server)
+        });
+
+        let (status, reply) = post(&address, "/day/1/part/1", "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet");
+        assert!(status.contains("200"));
+        assert_eq!(reply.answer.as_deref(), Some("142"));
+        assert!(reply.time.is_some());
+        assert_eq!(reply.error, None);
+
+        let (status, reply) = post(&address, "/day/2/part/2", "Game 1: three blue");
+        assert!(status.contains("422"));
+        assert_eq!(reply.error.as_deref(), Some("Error: Invalid cube count 'three'."));
+
+        server.unblock();
+        handle.join().unwrap();
+    }
+}
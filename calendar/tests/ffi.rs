@@ -0,0 +1,93 @@
+use std::{env, ffi::{c_char, CStr}, path::PathBuf, ptr};
+use libloading::{library_filename, Library, Symbol};
+
+type Solve = unsafe extern "C" fn(*const u8, usize, u32, u32, *mut *mut c_char) -> i32;
+type Free = unsafe extern "C" fn(*mut c_char);
+
+// the cdylib is built into the deps folder next to this test
+fn library() -> Library {
+    let mut path: PathBuf = env::current_exe().unwrap();
+    path.pop();
+    path.push(library_filename("calendar"));
+
+    unsafe { Library::new(&path) }.unwrap_or_else(|error| panic!("Error: Could not load {path:?} ({error})."))
+}
+
+fn solve(library: &Library, input: &str, day: u32, part: u32) -> (i32, Option<String>) {
+    unsafe {
+        let aoc_solve: Symbol<Solve> = library.get(b"aoc_solve").unwrap();
+        let aoc_free: Symbol<Free> = library.get(b"aoc_free").unwrap();
+
+        let mut result: *mut c_char = ptr::null_mut();
+        let status = aoc_solve(input.as_ptr(), input.len(), day, part, &mut result);
+
+        let answer = if result.is_null() {
+            None
+        } else {
+            let answer = CStr::from_ptr(result).to_str().unwrap().to_string();
+            aoc_free(result);
+            Some(answer)
+        };
+
+        (status, answer)
+    }
+}
+
+#[test]
+fn dlopen() {
+    let library = library();
+
+    let input = "???.### 1,1,3
+        .??..??...?##. 1,1,3
+        ?#?#?#?#?#?#?#? 1,3,1,6
+        ????.#...#... 4,1,1
+        ????.######..#####. 1,6,5
+        ?###???????? 3,2,1";
+    assert_eq!(solve(&library, input, 12, 2), (0, Some(String::from("525152"))));
+
+    let input = "2413432311323
+        3215453535623
+        3255245654254
+        3446585845452
+        4546657867536
+        1438598798454
+        4457876987766
+        3637877979653
+        4654967986887
+        4564679986453
+        1224686865563
+        2546548887735
+        4322674655533";
+    assert_eq!(solve(&library, input, 17, 1), (0, Some(String::from("102"))));
+
+    let input = "jqt: rhn xhk nvd
+        rsh: frs pzl lsr
+        xhk: hfx
+        cmg: qnr nvd lhk bvb
+        rhn: xhk bvb hfx
+        bvb: xhk hfx
+        pzl: lsr hfx nvd
+        qnr: nvd
+        ntq: jqt hfx bvb xhk
+        nvd: lhk
+        lsr: lhk
+        rzs: qnr cmg lsr rsh
+        frs: qnr lhk lsr";
+    assert_eq!(solve(&library, input, 25, 1), (0, Some(String::from("54"))));
+
+    assert_eq!(solve(&library, "Game 1: three blue", 2, 1), (4, Some(String::from("Error: Invalid cube count 'three'."))));
+    assert_eq!(solve(&library, "", 25, 2), (3, None));
+
+    unsafe {
+        let aoc_solve: Symbol<Solve> = library.get(b"aoc_solve").unwrap();
+        let aoc_free: Symbol<Free> = library.get(b"aoc_free").unwrap();
+        let mut result: *mut c_char = ptr::null_mut();
+        assert_eq!(aoc_solve(ptr::null(), 0, 1, 1, &mut result), 0);
+        assert_eq!(CStr::from_ptr(result).to_str(), Ok("0"));
+        aoc_free(result);
+        assert_eq!(aoc_solve(ptr::null(), 1, 1, 1, &mut result), 1);
+        assert_eq!(aoc_solve([0].as_ptr(), 0, 1, 1, ptr::null_mut()), 1);
+        assert_eq!(aoc_solve([0xff].as_ptr(), 1, 1, 1, &mut result), 2);
+        assert!(result.is_null());
+    }
+}
@@ -0,0 +1,17 @@
+#![cfg(feature = "ffi-header")]
+use std::{env, fs};
+
+// run with UPDATE_HEADER=1 to write the generated header instead of comparing
+#[test]
+fn header() {
+    let generated = include_str!(concat!(env!("OUT_DIR"), "/calendar.h"));
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/include/calendar.h");
+
+    if env::var_os("UPDATE_HEADER").is_some() {
+        fs::write(path, generated).unwrap();
+        return;
+    }
+
+    let committed = fs::read_to_string(path).unwrap();
+    assert!(committed == generated, "Error: include/calendar.h is out of date, rerun with UPDATE_HEADER=1.");
+}
@@ -0,0 +1,457 @@
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, VecDeque},
+    error::Error,
+    fmt,
+    fs::File,
+    io::{self, BufRead, BufReader, Seek, SeekFrom},
+    path::Path,
+    thread
+};
+
+// tokens that are recognised as digits, e.g. "7" -> 7 or "seven" -> 7
+#[derive(Clone, Debug, Default)]
+pub struct Vocabulary {
+    tokens: Vec<(String, u32)>
+}
+
+impl Vocabulary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // the digit characters 0 to 9
+    pub fn digits() -> Self {
+        (0..=9).fold(Self::new(), |vocabulary, digit| vocabulary.with(&digit.to_string(), digit))
+    }
+
+    // the digit characters 1 to 9 and their english spelling
+    pub fn english() -> Self {
+        let spelled = [
+            "one",
+            "two",
+            "three",
+            "four",
+            "five",
+            "six",
+            "seven",
+            "eight",
+            "nine"
+        ];
+
+        spelled
+            .iter()
+            .zip(1..)
+            .fold(Self::new(), |vocabulary, (spelling, digit)| vocabulary
+                .with(&digit.to_string(), digit)
+                .with(spelling, digit)
+            )
+    }
+
+    // adds a token, empty tokens are ignored
+    pub fn with(mut self, token: &str, value: u32) -> Self {
+        if !token.is_empty() {
+            self.tokens.push((token.to_string(), value));
+        }
+        self
+    }
+
+    pub fn extend(mut self, other: Vocabulary) -> Self {
+        self.tokens.extend(other.tokens);
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Match {
+    // byte offsets into the line
+    pub start: usize,
+    pub end: usize,
+    pub value: u32
+}
+
+#[derive(Debug, Default)]
+struct Node {
+    next: HashMap<u8, usize>,
+    fail: usize,
+    // indices of the tokens ending in this node
+    outputs: Vec<usize>
+}
+
+// Aho-Corasick automaton over the bytes of the vocabulary
+#[derive(Debug)]
+pub struct Recogniser {
+    nodes: Vec<Node>,
+    tokens: Vec<(usize, u32)>
+}
+
+impl Recogniser {
+    pub fn new(vocabulary: &Vocabulary) -> Self {
+        let mut nodes = vec![Node::default()];
+
+        // build the trie
+        for (i, (token, _)) in vocabulary.tokens.iter().enumerate() {
+            let mut node = 0;
+            for byte in token.bytes() {
+                node = match nodes[node].next.get(&byte) {
+                    Some(&next) => next,
+                    None => {
+                        nodes.push(Node::default());
+                        let next = nodes.len() - 1;
+                        nodes[node].next.insert(byte, next);
+                        next
+                    }
+                };
+            }
+            nodes[node].outputs.push(i);
+        }
+
+        // link each node to its longest proper suffix in the trie, breadth first
+        let mut queue: VecDeque<usize> = nodes[0].next.values().copied().collect();
+        while let Some(node) = queue.pop_front() {
+            let children: Vec<(u8, usize)> = nodes[node].next.iter().map(|(&byte, &child)| (byte, child)).collect();
+
+            for (byte, child) in children {
+                let mut fail = nodes[node].fail;
+                while fail != 0 && !nodes[fail].next.contains_key(&byte) {
+                    fail = nodes[fail].fail;
+                }
+                let fail = nodes[fail].next.get(&byte).copied().filter(|&fail| fail != child).unwrap_or(0);
+
+                nodes[child].fail = fail;
+                let outputs = nodes[fail].outputs.clone();
+                nodes[child].outputs.extend(outputs);
+
+                queue.push_back(child);
+            }
+        }
+
+        Recogniser {
+            nodes,
+            tokens: vocabulary.tokens.iter().map(|(token, value)| (token.len(), *value)).collect()
+        }
+    }
+
+    fn step(&self, mut node: usize, byte: u8) -> usize {
+        loop {
+            if let Some(&next) = self.nodes[node].next.get(&byte) {
+                return next;
+            }
+            if node == 0 {
+                return 0;
+            }
+            node = self.nodes[node].fail;
+        }
+    }
+
+    // all matches in a single pass, overlapping ones included, ordered by their end
+    pub fn matches<'a>(&'a self, line: &'a str) -> impl Iterator<Item = Match> + 'a {
+        let mut node = 0;
+
+        line.bytes().enumerate().flat_map(move |(i, byte)| {
+            node = self.step(node, byte);
+
+            self.nodes[node].outputs.iter().map(move |&token| {
+                let (len, value) = self.tokens[token];
+                Match {
+                    start: i + 1 - len,
+                    end: i + 1,
+                    value
+                }
+            })
+        })
+    }
+
+    // the match starting first and the match starting last, the longer one wins a tie
+    pub fn first_last(&self, line: &str) -> Option<(Match, Match)> {
+        self.matches(line).fold(None, |first_last, found| match first_last {
+            None => Some((found, found)),
+            Some((first, last)) => {
+                let first = if (found.start, Reverse(found.end)) < (first.start, Reverse(first.end)) { found } else { first };
+                let last = if (found.start, found.end) > (last.start, last.end) { found } else { last };
+                Some((first, last))
+            }
+        })
+    }
+}
+
+// the calibration value of two token values, u64 holds it for any pair of u32 values
+fn line_value(first: u32, last: u32) -> u64 {
+    10 * u64::from(first) + u64::from(last)
+}
+
+pub fn calibrate(input: &str, recogniser: &Recogniser) -> Result<u64, CalibrationError> {
+    calibrate_reader(input.as_bytes(), recogniser)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub start: usize,
+    pub end: usize,
+    pub text: &'a str,
+    pub value: u32
+}
+
+impl<'a> Token<'a> {
+    fn from(found: Match, line: &'a str) -> Self {
+        Token {
+            start: found.start,
+            end: found.end,
+            text: &line[found.start..found.end],
+            value: found.value
+        }
+    }
+}
+
+impl fmt::Display for Token<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}@{}..{}", self.text, self.start, self.end)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Calibration<'a> {
+    Digits(Token<'a>, Token<'a>),
+    NoDigit
+}
+
+impl Calibration<'_> {
+    pub fn value(&self) -> Option<u64> {
+        match self {
+            Calibration::Digits(first, last) => Some(line_value(first.value, last.value)),
+            Calibration::NoDigit => None
+        }
+    }
+}
+
+impl fmt::Display for Calibration<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Calibration::Digits(first, last) => write!(f, "{first} {last} = {}", line_value(first.value, last.value)),
+            Calibration::NoDigit => write!(f, "warning: no digit")
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Explanation<'a> {
+    // counted from 1, empty lines are skipped but still counted
+    pub number: usize,
+    pub line: &'a str,
+    pub calibration: Calibration<'a>
+}
+
+// the first and last match of every line, offsets are bytes into the line
+pub fn explain<'a>(input: &'a str, recogniser: &Recogniser) -> Vec<Explanation<'a>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| Explanation {
+            number: i + 1,
+            line,
+            calibration: match recogniser.first_last(line) {
+                Some((first, last)) => Calibration::Digits(Token::from(first, line), Token::from(last, line)),
+                None => Calibration::NoDigit
+            }
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+pub enum CalibrationError {
+    Io(io::Error),
+    Overflow
+}
+
+impl fmt::Display for CalibrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalibrationError::Io(error) => write!(f, "Error: Could not read the document ({error})."),
+            CalibrationError::Overflow => write!(f, "Error: The calibration sum does not fit into 64 bits.")
+        }
+    }
+}
+
+impl Error for CalibrationError {}
+
+impl From<io::Error> for CalibrationError {
+    fn from(error: io::Error) -> Self {
+        CalibrationError::Io(error)
+    }
+}
+
+// sums the calibration values of the lines read until the reader has consumed more than limit bytes
+fn calibrate_lines<R: BufRead>(mut reader: R, recogniser: &Recogniser, limit: u64) -> Result<u64, CalibrationError> {
+    let mut line = String::new();
+    let mut consumed = 0;
+    let mut sum: u64 = 0;
+
+    while consumed < limit {
+        line.clear();
+        let read = reader.read_line(&mut line)?;
+        if read == 0 {
+            break;
+        }
+        consumed += read as u64;
+
+        if let Some((first, last)) = recogniser.first_last(line.trim_end_matches(['\n', '\r'])) {
+            sum = sum
+                .checked_add(line_value(first.value, last.value))
+                .ok_or(CalibrationError::Overflow)?;
+        }
+    }
+
+    Ok(sum)
+}
+
+// streams the document line by line, only the current line is kept in memory
+pub fn calibrate_reader<R: BufRead>(reader: R, recogniser: &Recogniser) -> Result<u64, CalibrationError> {
+    calibrate_lines(reader, recogniser, u64::MAX)
+}
+
+// sums the lines starting in [start, end) of the file
+fn calibrate_chunk(path: &Path, recogniser: &Recogniser, start: u64, end: u64) -> Result<u64, CalibrationError> {
+    let mut file = File::open(path)?;
+    let mut consumed = 0;
+
+    // skip the line started in the previous chunk
+    if start > 0 {
+        file.seek(SeekFrom::Start(start - 1))?;
+        let mut reader = BufReader::new(&mut file);
+        consumed = reader.skip_until(b'\n')? as u64 - 1;
+        let position = start + consumed;
+        file.seek(SeekFrom::Start(position))?;
+    }
+
+    calibrate_lines(BufReader::new(file), recogniser, (end - start).saturating_sub(consumed))
+}
+
+// streams the file in as many chunks as there are threads, split at the line after each chunk boundary
+pub fn calibrate_file(path: impl AsRef<Path>, recogniser: &Recogniser, threads: usize) -> Result<u64, CalibrationError> {
+    let path = path.as_ref();
+    let len = path.metadata()?.len();
+    let threads = threads.max(1) as u64;
+
+    if threads == 1 {
+        return calibrate_reader(BufReader::new(File::open(path)?), recogniser);
+    }
+
+    let bounds: Vec<u64> = (0..=threads).map(|i| len * i / threads).collect();
+
+    thread::scope(|scope| {
+        let chunks: Vec<_> = bounds
+            .windows(2)
+            .map(|bound| scope.spawn(move || calibrate_chunk(path, recogniser, bound[0], bound[1])))
+            .collect();
+
+        chunks
+            .into_iter()
+            .try_fold(0_u64, |sum, chunk| {
+                let chunk = chunk.join().expect("Error: Calibration thread panicked.")?;
+                sum.checked_add(chunk).ok_or(CalibrationError::Overflow)
+            })
+    })
+}
+
+pub fn part1(input: &str) -> u64 {
+    calibrate(input, &Recogniser::new(&Vocabulary::digits())).unwrap_or_else(|error| panic!("{error}"))
+}
+
+pub fn part2(input: &str) -> u64 {
+    calibrate(input, &Recogniser::new(&Vocabulary::english())).unwrap_or_else(|error| panic!("{error}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1() {
+        let input = "1abc2
+            pqr3stu8vwx
+            a1b2c3d4e5f
+            treb7uchet";
+        assert_eq!(part1(input), 142);
+    }
+
+    #[test]
+    fn part_2() {
+        let input = "two1nine
+            eightwothree
+            abcone2threexyz
+            xtwone3four
+            4nineeightseven2
+            zoneight234
+            7pqrstsixteen";
+        assert_eq!(part2(input), 281);
+    }
+
+    #[test]
+    fn overlaps() {
+        let recogniser = Recogniser::new(&Vocabulary::english());
+        let (first, last) = recogniser.first_last("eightwo").unwrap();
+        assert_eq!((first.value, last.value), (8, 2));
+        assert_eq!((first.start, first.end, last.start, last.end), (0, 5, 4, 7));
+
+        assert_eq!(recogniser.matches("oneight").map(|found| found.value).collect::<Vec<_>>(), vec![1, 8]);
+        assert_eq!(recogniser.first_last("abc"), None);
+    }
+
+    #[test]
+    fn explanation() {
+        let input = "two1nine
+            abc
+
+            7pqrstsixteen";
+        let explanations = explain(input, &Recogniser::new(&Vocabulary::english()));
+
+        assert_eq!(explanations.len(), 3);
+        assert_eq!(explanations[0].calibration, Calibration::Digits(
+            Token { start: 0, end: 3, text: "two", value: 2 },
+            Token { start: 4, end: 8, text: "nine", value: 9 }
+        ));
+        assert_eq!(explanations[0].calibration.to_string(), r#""two"@0..3 "nine"@4..8 = 29"#);
+        assert_eq!(explanations[1].calibration, Calibration::NoDigit);
+        assert_eq!(explanations[2].number, 4);
+        assert_eq!(explanations[2].calibration.value(), Some(76));
+    }
+
+    #[test]
+    fn stream() {
+        let input = "two1nine\r\neightwothree\nabcone2threexyz\n\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen";
+        let recogniser = Recogniser::new(&Vocabulary::english());
+        assert_eq!(calibrate_reader(io::Cursor::new(input), &recogniser).unwrap(), 281);
+
+        let path = std::env::temp_dir().join(format!("day01-stream-{}.txt", std::process::id()));
+        std::fs::write(&path, format!("{input}\n").repeat(3)).unwrap();
+        for threads in 1..=20 {
+            assert_eq!(calibrate_file(&path, &recogniser, threads).unwrap(), 281 * 3, "{threads} threads");
+        }
+        std::fs::remove_file(&path).unwrap();
+
+        // token values up to u32::MAX neither overflow a line nor the sum
+        let recogniser = Recogniser::new(&Vocabulary::new().with("max", u32::MAX));
+        assert_eq!(calibrate("max\nmax", &recogniser).unwrap(), 2 * 11 * u64::from(u32::MAX));
+    }
+
+    #[test]
+    fn vocabulary() {
+        let vocabulary = Vocabulary::new()
+            .with("zero", 0)
+            .with("un", 1)
+            .with("deux", 2)
+            .with("drei", 3)
+            .with("\u{FF17}", 7)
+            .with("", 5)
+            .extend(Vocabulary::digits());
+        let recogniser = Recogniser::new(&vocabulary);
+
+        assert_eq!(calibrate("xzerodeux9", &recogniser).unwrap(), 9);
+        assert_eq!(calibrate("dreiundeux", &recogniser).unwrap(), 32);
+        assert_eq!(calibrate("a\u{FF17}b", &recogniser).unwrap(), 77);
+
+        let (first, _) = recogniser.first_last("é\u{FF17}").unwrap();
+        assert_eq!((first.start, first.end), (2, 5));
+    }
+}
\ No newline at end of file
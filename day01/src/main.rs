@@ -1,101 +1,25 @@
-use std::{error::Error, fs};
+use std::{env, error::Error, fs, thread};
+use day01::{calibrate_file, explain, Recogniser, Vocabulary};
 
-fn part1(input: &str) -> u32 {
-    input
-        .lines()
-        .filter(|line| !line.is_empty())
-        .fold(0, |acc, line| {
-            let digits: Vec<u32> = line
-                .chars()
-                .filter_map(|c| c.to_digit(10))
-                .collect();
-
-            match digits.first() {
-                Some(first) => acc + 10 * first + digits.last().unwrap(),
-                None => acc
-            }
-        })
-}
-
-fn part2(input: &str) -> u32 {
-    let spelled = [
-        "one",
-        "two",
-        "three",
-        "four",
-        "five",
-        "six",
-        "seven",
-        "eight",
-        "nine"
-    ];
-    
-    input
-        .lines()
-        .filter(|line| !line.is_empty())
-        .fold(0, |acc, line| {
-            let mut digits: Vec<(usize, u32)> = Vec::new();
-
-            for digit in 1..=9 {
-                if let Some(i) = line.find(char::from_digit(digit, 10).unwrap()) {
-                    digits.push((i, digit));
-                }
-    
-                if let Some(i) = line.rfind(char::from_digit(digit, 10).unwrap()) {
-                    digits.push((i, digit));
-                }
-            }
-
-            for (digit, spelling) in spelled.iter().enumerate() {
-                if let Some(i) = line.find(spelling) {
-                    digits.push((i, (digit + 1) as u32));
-                }
-
-                if let Some(i) = line.rfind(spelling) {
-                    digits.push((i, (digit + 1) as u32));
-                }
-            }
-
-            digits.sort_by(|a, b| a.0.cmp(&b.0));
+fn main() -> Result<(), Box<dyn Error>> {
+    // print how every line was calibrated and flag the lines where the parts disagree
+    if env::args().any(|arg| arg == "--explain") {
+        let input = fs::read_to_string("input.txt")?;
+        let digits = explain(&input, &Recogniser::new(&Vocabulary::digits()));
+        let english = explain(&input, &Recogniser::new(&Vocabulary::english()));
+
+        println!("{:>5} | {:<40} | {:<40} |", "line", "part 1", "part 2");
+        for (one, two) in digits.iter().zip(english.iter()) {
+            let flag = if one.calibration.value() != two.calibration.value() { "differs" } else { "" };
+            println!("{:>5} | {:<40} | {:<40} | {}", one.number, one.calibration.to_string(), two.calibration.to_string(), flag);
+        }
+    }
 
-            match digits.first() {
-                Some(first) => acc + 10 * first.1 + digits.last().unwrap().1,
-                None => acc
-            }
-        })
-}
+    // stream the document so it never has to fit into memory
+    let threads = thread::available_parallelism().map_or(1, |threads| threads.get());
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = fs::read_to_string("input.txt")?;
-    
-    dbg!(part1(&input));
-    dbg!(part2(&input));
+    dbg!(calibrate_file("input.txt", &Recogniser::new(&Vocabulary::digits()), threads)?);
+    dbg!(calibrate_file("input.txt", &Recogniser::new(&Vocabulary::english()), threads)?);
 
     Ok(())
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part_1() {
-        let input = "1abc2
-            pqr3stu8vwx
-            a1b2c3d4e5f
-            treb7uchet";
-        assert_eq!(part1(input), 142);
-    }
-
-    #[test]
-    fn part_2() {
-        let input = "two1nine
-            eightwothree
-            abcone2threexyz
-            xtwone3four
-            4nineeightseven2
-            zoneight234
-            7pqrstsixteen";
-        assert_eq!(part2(input), 281);
-    }
-}
\ No newline at end of file
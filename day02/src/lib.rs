@@ -0,0 +1,415 @@
+use std::{collections::{BTreeMap, BTreeSet}, error::Error, fmt, str::FromStr};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseGameError {
+    MissingId,
+    InvalidId(String),
+    InvalidCount(String),
+    InvalidColor(String)
+}
+
+impl fmt::Display for ParseGameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseGameError::MissingId => write!(f, "Error: Missing 'Game <id>:' prefix."),
+            ParseGameError::InvalidId(id) => write!(f, "Error: Invalid game id '{id}'."),
+            ParseGameError::InvalidCount(count) => write!(f, "Error: Invalid cube count '{count}'."),
+            ParseGameError::InvalidColor(color) => write!(f, "Error: Invalid color '{color}'.")
+        }
+    }
+}
+
+impl Error for ParseGameError {}
+
+// counts per color in "3 blue, 4 red" notation, repeated colors add up
+fn parse_cubes(s: &str) -> Result<BTreeMap<String, u32>, ParseGameError> {
+    let mut cubes = BTreeMap::new();
+
+    for color in s.split(',').map(|color| color.trim()).filter(|color| !color.is_empty()) {
+        let (num_cubes, name) = color
+            .split_once(' ')
+            .ok_or(ParseGameError::InvalidColor(color.to_string()))?;
+
+        let num_cubes: u32 = num_cubes
+            .parse()
+            .map_err(|_| ParseGameError::InvalidCount(num_cubes.to_string()))?;
+
+        let name = name.trim();
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            return Err(ParseGameError::InvalidColor(name.to_string()));
+        }
+
+        *cubes.entry(name.to_string()).or_insert(0) += num_cubes;
+    }
+
+    Ok(cubes)
+}
+
+// cubes of each color shown in one reach into the bag
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Reveal(pub BTreeMap<String, u32>);
+
+impl Reveal {
+    pub fn get(&self, color: &str) -> u32 {
+        self.0.get(color).copied().unwrap_or(0)
+    }
+}
+
+impl FromStr for Reveal {
+    type Err = ParseGameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_cubes(s).map(Reveal)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Game {
+    pub id: u32,
+    pub reveals: Vec<Reveal>
+}
+
+impl FromStr for Game {
+    type Err = ParseGameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (game, reveals) = s.trim().split_once(':').ok_or(ParseGameError::MissingId)?;
+
+        let id = game
+            .strip_prefix("Game")
+            .ok_or(ParseGameError::MissingId)?
+            .trim();
+        let id = id
+            .parse()
+            .map_err(|_| ParseGameError::InvalidId(id.to_string()))?;
+
+        let reveals = reveals
+            .split(';')
+            .filter(|reveal| !reveal.trim().is_empty())
+            .map(|reveal| reveal.parse())
+            .collect::<Result<_, _>>()?;
+
+        Ok(Game {
+            id,
+            reveals
+        })
+    }
+}
+
+// how many cubes of each color are in the bag, written like a reveal
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bag(pub BTreeMap<String, u32>);
+
+impl Bag {
+    // 12 red, 13 green and 14 blue cubes
+    pub fn standard() -> Self {
+        Bag::default()
+            .with("red", 12)
+            .with("green", 13)
+            .with("blue", 14)
+    }
+
+    pub fn with(mut self, color: &str, num_cubes: u32) -> Self {
+        self.0.insert(color.to_string(), num_cubes);
+        self
+    }
+
+    // colors that are not in the bag have no cubes
+    pub fn get(&self, color: &str) -> u32 {
+        self.0.get(color).copied().unwrap_or(0)
+    }
+
+    pub fn colors(&self) -> BTreeSet<String> {
+        self.0.keys().cloned().collect()
+    }
+
+    // product of the cube counts of every color in the bag
+    pub fn power(&self) -> u64 {
+        self.0.values().map(|&num_cubes| num_cubes as u64).product()
+    }
+}
+
+impl FromStr for Bag {
+    type Err = ParseGameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_cubes(s).map(Bag)
+    }
+}
+
+pub fn is_possible(game: &Game, bag: &Bag) -> bool {
+    game.reveals
+        .iter()
+        .all(|reveal| reveal.0
+            .iter()
+            .all(|(color, &num_cubes)| num_cubes <= bag.get(color))
+        )
+}
+
+// every color shown in any of the games
+pub fn colors(games: &[Game]) -> BTreeSet<String> {
+    games
+        .iter()
+        .flat_map(|game| game.reveals.iter())
+        .flat_map(|reveal| reveal.0.keys().cloned())
+        .collect()
+}
+
+// the fewest cubes of each color that make the game possible
+// colors the game never shows are in the bag with 0 cubes
+pub fn minimal_bag(game: &Game, colors: &BTreeSet<String>) -> Bag {
+    let mut bag = Bag(colors.iter().map(|color| (color.clone(), 0)).collect());
+
+    for reveal in game.reveals.iter() {
+        for (color, &num_cubes) in reveal.0.iter() {
+            let max = bag.0.entry(color.clone()).or_insert(0);
+            *max = (*max).max(num_cubes);
+        }
+    }
+
+    bag
+}
+
+// the smallest bag every game is possible with, any bag holding at least these cubes is consistent
+pub fn consistent_bag(games: &[Game]) -> Bag {
+    let mut bag = Bag::default();
+
+    for game in games.iter() {
+        for (color, num_cubes) in minimal_bag(game, &BTreeSet::new()).0 {
+            let max = bag.0.entry(color).or_insert(0);
+            *max = (*max).max(num_cubes);
+        }
+    }
+
+    bag
+}
+
+fn distribute(colors: &[(&String, u32)], remaining: u32, bag: &mut Bag, bags: &mut Vec<Bag>) {
+    match colors {
+        [] => if remaining == 0 {
+            bags.push(bag.clone());
+        },
+        [(color, min)] => if remaining >= *min {
+            bag.0.insert(color.to_string(), remaining);
+            bags.push(bag.clone());
+        },
+        [(color, min), rest @ ..] => {
+            let rest_min: u32 = rest.iter().map(|(_, min)| min).sum();
+            for num_cubes in *min..=remaining.saturating_sub(rest_min) {
+                bag.0.insert(color.to_string(), num_cubes);
+                distribute(rest, remaining - num_cubes, bag, bags);
+            }
+        }
+    }
+}
+
+// every bag of the observed colors with exactly total cubes that all games are possible with
+pub fn consistent_bags(games: &[Game], total: u32) -> Vec<Bag> {
+    let bounds = consistent_bag(games);
+    let colors: Vec<(&String, u32)> = bounds.0.iter().map(|(color, &min)| (color, min)).collect();
+
+    let mut bags = Vec::new();
+    distribute(&colors, total, &mut Bag::default(), &mut bags);
+    bags
+}
+
+// log of how likely the reveals are if each one draws its cubes from the bag without putting them back
+// the odds of drawing any handful of that size are left out, they are the same for every bag of the same total
+fn log_likelihood(games: &[Game], bag: &Bag, ln_factorials: &[f64]) -> f64 {
+    let ln_binomial = |n: u32, k: u32| ln_factorials[n as usize] - ln_factorials[k as usize] - ln_factorials[(n - k) as usize];
+
+    games
+        .iter()
+        .flat_map(|game| game.reveals.iter())
+        .flat_map(|reveal| reveal.0.iter())
+        .map(|(color, &num_cubes)| ln_binomial(bag.get(color), num_cubes))
+        .sum()
+}
+
+// the bag with total cubes that makes the observed reveals most likely, None if no such bag is consistent
+pub fn most_likely_bag(games: &[Game], total: u32) -> Option<Bag> {
+    let ln_factorials: Vec<f64> = (0..=total)
+        .scan(0.0, |ln_factorial, n| {
+            if n > 0 {
+                *ln_factorial += (n as f64).ln();
+            }
+            Some(*ln_factorial)
+        })
+        .collect();
+
+    consistent_bags(games, total)
+        .into_iter()
+        .map(|bag| (log_likelihood(games, &bag, &ln_factorials), bag))
+        .fold(None, |best: Option<(f64, Bag)>, (likelihood, bag)| match best {
+            Some(best) if best.0 >= likelihood => Some(best),
+            _ => Some((likelihood, bag))
+        })
+        .map(|(_, bag)| bag)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Constraint {
+    // the most cubes of the color shown at once
+    pub num_cubes: u32,
+    // the games showing that many
+    pub games: Vec<u32>
+}
+
+// which games force the lower bound of each color in the consistent bag
+pub fn constraints(games: &[Game]) -> BTreeMap<String, Constraint> {
+    let mut constraints: BTreeMap<String, Constraint> = BTreeMap::new();
+
+    for game in games.iter() {
+        for (color, num_cubes) in minimal_bag(game, &BTreeSet::new()).0 {
+            let constraint = constraints.entry(color).or_insert(Constraint {
+                num_cubes,
+                games: Vec::new()
+            });
+
+            if num_cubes > constraint.num_cubes {
+                constraint.num_cubes = num_cubes;
+                constraint.games.clear();
+            }
+            if num_cubes == constraint.num_cubes {
+                constraint.games.push(game.id);
+            }
+        }
+    }
+
+    constraints
+}
+
+pub fn read_games(input: &str) -> Result<Vec<Game>, ParseGameError> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.parse())
+        .collect()
+}
+
+pub fn sum_possible(games: &[Game], bag: &Bag) -> u32 {
+    games
+        .iter()
+        .filter(|game| is_possible(game, bag))
+        .map(|game| game.id)
+        .sum()
+}
+
+// the powers of the minimal bags over the given colors and all colors the games show
+pub fn sum_powers(games: &[Game], colors: &BTreeSet<String>) -> u64 {
+    let mut colors = colors.clone();
+    colors.extend(self::colors(games));
+
+    games
+        .iter()
+        .map(|game| minimal_bag(game, &colors).power())
+        .sum()
+}
+
+pub fn part1(input: &str) -> u32 {
+    let games = read_games(input).unwrap_or_else(|error| panic!("{error}"));
+    sum_possible(&games, &Bag::standard())
+}
+
+pub fn part2(input: &str) -> u64 {
+    let games = read_games(input).unwrap_or_else(|error| panic!("{error}"));
+    sum_powers(&games, &Bag::standard().colors())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+        assert_eq!(part1(input), 8);
+    }
+
+    #[test]
+    fn part_2() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+        assert_eq!(part2(input), 2286);
+    }
+
+    #[test]
+    fn game_ids() {
+        let input = "Game 7: 3 blue, 4 red
+        Game 3: 20 red
+        Game 12: 1 green";
+        assert_eq!(part1(input), 19);
+
+        assert_eq!("Game one: 3 blue".parse::<Game>(), Err(ParseGameError::InvalidId(String::from("one"))));
+        assert_eq!("Game 1: three blue".parse::<Game>(), Err(ParseGameError::InvalidCount(String::from("three"))));
+        assert_eq!("3 blue".parse::<Game>(), Err(ParseGameError::MissingId));
+    }
+
+    #[test]
+    fn colors() {
+        let input = "Game 1: 3 blue, 4 red; 2 yellow, 1 red
+        Game 2: 1 blue, 8 yellow
+        Game 3: 1 purple";
+        let games = read_games(input).unwrap();
+        let bag: Bag = "12 red, 13 green, 14 blue, 5 yellow".parse().unwrap();
+
+        assert!(is_possible(&games[0], &bag));
+        assert!(!is_possible(&games[1], &bag));
+        assert!(!is_possible(&games[2], &bag));
+        assert_eq!(sum_possible(&games, &bag), 1);
+
+        let shown = Bag::default().with("blue", 3).with("red", 4).with("yellow", 2);
+        assert_eq!(minimal_bag(&games[0], &BTreeSet::new()), shown);
+        assert_eq!(minimal_bag(&games[0], &BTreeSet::new()).power(), 24);
+        assert_eq!(minimal_bag(&games[0], &super::colors(&games)), shown.clone().with("purple", 0));
+        assert_eq!(sum_powers(&games[..1], &BTreeSet::new()), 24);
+        // every game misses a color another game shows
+        assert_eq!(sum_powers(&games, &BTreeSet::new()), 0);
+
+        // a color a game never shows needs 0 cubes
+        assert_eq!(part2("Game 1: 3 red, 4 blue"), 0);
+        assert_eq!(part2("Game 1: 3 red, 4 blue, 1 green"), 12);
+        assert_eq!(sum_powers(&games[..1], &Bag::standard().colors()), 0);
+
+        assert_eq!("12 red, 13 green, 14 blue".parse::<Bag>().unwrap(), Bag::standard());
+        assert_eq!("12".parse::<Bag>(), Err(ParseGameError::InvalidColor(String::from("12"))));
+    }
+
+    #[test]
+    fn inference() {
+        let input = "Game 1: 2 red, 1 blue; 2 red, 1 blue
+        Game 2: 1 red; 1 blue
+        Game 3: 2 red, 1 blue";
+        let games = read_games(input).unwrap();
+
+        assert_eq!(consistent_bag(&games), Bag::default().with("red", 2).with("blue", 1));
+        assert_eq!(consistent_bags(&games, 4).len(), 2);
+        assert!(consistent_bags(&games, 2).is_empty());
+
+        assert_eq!(most_likely_bag(&games, 6), Some(Bag::default().with("red", 4).with("blue", 2)));
+        assert_eq!(most_likely_bag(&games, 2), None);
+
+        let constraints = constraints(&games);
+        assert_eq!(constraints["red"], Constraint { num_cubes: 2, games: vec![1, 3] });
+        assert_eq!(constraints["blue"], Constraint { num_cubes: 1, games: vec![1, 2, 3] });
+    }
+
+    #[test]
+    fn json() {
+        let game: Game = "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red".parse().unwrap();
+        assert_eq!(game.reveals[1].get("red"), 6);
+        assert_eq!(game.reveals[1].get("green"), 3);
+        assert_eq!(game.reveals[1].get("blue"), 0);
+
+        let json = serde_json::to_string(&game).unwrap();
+        assert_eq!(serde_json::from_str::<Game>(&json).unwrap(), game);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,425 @@
+// a number spanning the columns [start, end) of a row
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Number {
+    pub row: usize,
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+    // the digits as written, leading zeros included
+    pub text: String
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Symbol {
+    pub row: usize,
+    pub col: usize,
+    pub char: char
+}
+
+impl Number {
+    pub fn is_adjacent_to(&self, symbol: &Symbol) -> bool {
+        self.row.abs_diff(symbol.row) <= 1 && self.start <= symbol.col + 1 && symbol.col <= self.end
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Schematic {
+    pub numbers: Vec<Number>,
+    pub symbols: Vec<Symbol>,
+    // indices of the numbers and symbols on each row
+    numbers_by_row: Vec<Vec<usize>>,
+    symbols_by_row: Vec<Vec<usize>>,
+    widths: Vec<usize>
+}
+
+impl From<&str> for Schematic {
+    fn from(input: &str) -> Self {
+        let mut schematic = Schematic::default();
+
+        // blank lines only count as rows between other rows
+        let lines: Vec<&str> = input.lines().map(|line| line.trim()).collect();
+        let first = lines.iter().position(|line| !line.is_empty()).unwrap_or(lines.len());
+        let last = lines.iter().rposition(|line| !line.is_empty()).map_or(first, |last| last + 1);
+
+        for (row, line) in lines[first..last].iter().enumerate() {
+
+            schematic.numbers_by_row.push(Vec::new());
+            schematic.symbols_by_row.push(Vec::new());
+
+            let chars: Vec<char> = line.chars().collect();
+            schematic.widths.push(chars.len());
+
+            let mut start = 0;
+            while start < chars.len() {
+
+                let mut end = start;
+                while end < chars.len() && chars[end].is_ascii_digit() {
+                    end += 1;
+                }
+
+                if end > start {
+                    let text: String = chars[start..end].iter().collect();
+                    schematic.numbers_by_row[row].push(schematic.numbers.len());
+                    schematic.numbers.push(Number {
+                        row,
+                        start,
+                        end,
+                        value: text.parse().unwrap(),
+                        text
+                    });
+                    start = end;
+                } else {
+                    if chars[start] != '.' {
+                        schematic.symbols_by_row[row].push(schematic.symbols.len());
+                        schematic.symbols.push(Symbol {
+                            row,
+                            col: start,
+                            char: chars[start]
+                        });
+                    }
+                    start += 1;
+                }
+            }
+        }
+
+        schematic
+    }
+}
+
+impl Schematic {
+    // indices of the rows around row that exist
+    fn rows_around(&self, row: usize) -> std::ops::Range<usize> {
+        row.saturating_sub(1)..(row + 2).min(self.numbers_by_row.len())
+    }
+
+    pub fn numbers_adjacent_to(&self, symbol: &Symbol) -> Vec<&Number> {
+        self.rows_around(symbol.row)
+            .flat_map(|row| self.numbers_by_row[row].iter())
+            .map(|&i| &self.numbers[i])
+            .filter(|number| number.is_adjacent_to(symbol))
+            .collect()
+    }
+
+    pub fn symbols_adjacent_to(&self, number: &Number) -> Vec<&Symbol> {
+        self.rows_around(number.row)
+            .flat_map(|row| self.symbols_by_row[row].iter())
+            .map(|&i| &self.symbols[i])
+            .filter(|symbol| number.is_adjacent_to(symbol))
+            .collect()
+    }
+
+    // numbers next to at least one symbol
+    pub fn part_numbers(&self) -> Vec<&Number> {
+        self.numbers
+            .iter()
+            .filter(|number| !self.symbols_adjacent_to(number).is_empty())
+            .collect()
+    }
+
+    // symbols of the given char next to exactly n_parts numbers, with those numbers
+    pub fn gears(&self, symbol_char: char, n_parts: usize) -> Vec<(&Symbol, Vec<&Number>)> {
+        self.symbols
+            .iter()
+            .filter(|symbol| symbol.char == symbol_char)
+            .map(|symbol| (symbol, self.numbers_adjacent_to(symbol)))
+            .filter(|(_, numbers)| numbers.len() == n_parts)
+            .collect()
+    }
+}
+
+// how a cell of the schematic is highlighted
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Highlight {
+    Part,
+    Ignored,
+    Gear(u32),
+    Symbol,
+    Empty
+}
+use Highlight::*;
+
+impl Highlight {
+    fn ansi(&self) -> &'static str {
+        match self {
+            Part => "\x1b[1;32m",
+            Ignored => "\x1b[2;31m",
+            Gear(_) => "\x1b[1;33m",
+            Symbol => "\x1b[36m",
+            Empty => "\x1b[2m"
+        }
+    }
+
+    fn class(&self) -> &'static str {
+        match self {
+            Part => "part",
+            Ignored => "ignored",
+            Gear(_) => "gear",
+            Symbol => "symbol",
+            Empty => "empty"
+        }
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+impl Schematic {
+    // splits every row into highlighted runs of text, gears are the symbol_char symbols next to n_parts numbers
+    pub fn annotate(&self, symbol_char: char, n_parts: usize) -> Vec<Vec<(Highlight, String)>> {
+        let mut cells: Vec<Vec<(Highlight, char)>> = self.widths
+            .iter()
+            .map(|&width| vec![(Empty, '.'); width])
+            .collect();
+
+        for number in self.numbers.iter() {
+            let highlight = if self.symbols_adjacent_to(number).is_empty() { Ignored } else { Part };
+            for (col, digit) in (number.start..number.end).zip(number.text.chars()) {
+                cells[number.row][col] = (highlight, digit);
+            }
+        }
+
+        for symbol in self.symbols.iter() {
+            cells[symbol.row][symbol.col] = (Symbol, symbol.char);
+        }
+
+        for (symbol, numbers) in self.gears(symbol_char, n_parts) {
+            let ratio = numbers.iter().map(|number| number.value).product();
+            cells[symbol.row][symbol.col] = (Gear(ratio), symbol.char);
+        }
+
+        // merge neighbouring cells with the same highlight
+        cells
+            .into_iter()
+            .map(|row| {
+                let mut runs: Vec<(Highlight, String)> = Vec::new();
+                for (highlight, c) in row {
+                    match runs.last_mut() {
+                        Some((last, text)) if *last == highlight && !matches!(highlight, Gear(_)) => text.push(c),
+                        _ => runs.push((highlight, c.to_string()))
+                    }
+                }
+                runs
+            })
+            .collect()
+    }
+
+    // reprints the schematic with terminal colours and the gear ratios at the end of their rows
+    pub fn render_ansi(&self, symbol_char: char, n_parts: usize) -> String {
+        self.annotate(symbol_char, n_parts)
+            .iter()
+            .map(|row| {
+                let mut line: String = row
+                    .iter()
+                    .map(|(highlight, text)| format!("{}{}\x1b[0m", highlight.ansi(), text))
+                    .collect();
+
+                let ratios: Vec<String> = row
+                    .iter()
+                    .filter_map(|(highlight, _)| match highlight {
+                        Gear(ratio) => Some(ratio.to_string()),
+                        _ => None
+                    })
+                    .collect();
+                if !ratios.is_empty() {
+                    line.push_str(&format!("  {}{}\x1b[0m", Gear(0).ansi(), ratios.join(" ")));
+                }
+
+                line
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    // the same rendering as a standalone html page, gears show their ratio on hover
+    pub fn render_html(&self, symbol_char: char, n_parts: usize) -> String {
+        let rows: Vec<String> = self.annotate(symbol_char, n_parts)
+            .iter()
+            .map(|row| row
+                .iter()
+                .map(|(highlight, text)| match highlight {
+                    Gear(ratio) => format!("<span class=\"gear\" title=\"ratio {ratio}\">{}</span>", escape(text)),
+                    _ => format!("<span class=\"{}\">{}</span>", highlight.class(), escape(text))
+                })
+                .collect())
+            .collect();
+
+        format!(
+            "<!DOCTYPE html>
+<html>
+<head>
+<meta charset=\"utf-8\">
+<title>Engine schematic</title>
+<style>
+body {{ background: #0f0f23; color: #cccccc; }}
+.part {{ color: #00cc00; font-weight: bold; }}
+.ignored {{ color: #cc3333; opacity: 0.6; }}
+.gear {{ color: #ffff66; font-weight: bold; }}
+.symbol {{ color: #66cccc; }}
+.empty {{ opacity: 0.4; }}
+</style>
+</head>
+<body>
+<pre>
+{}
+</pre>
+</body>
+</html>
+",
+            rows.join("\n")
+        )
+    }
+}
+
+pub fn part1(input: &str) -> u32 {
+    Schematic::from(input)
+        .part_numbers()
+        .iter()
+        .map(|number| number.value)
+        .sum()
+}
+
+pub fn part2(input: &str) -> u32 {
+    Schematic::from(input)
+        .gears('*', 2)
+        .iter()
+        .map(|(_, numbers)| numbers.iter().map(|number| number.value).product::<u32>())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1() {
+        let input = "467..114..
+        ...*......
+        ..35..633.
+        ......#...
+        617*......
+        .....+.58.
+        ..592.....
+        ......755.
+        ...$.*....
+        .664.598..";
+        assert_eq!(part1(input), 4361);
+    }
+
+    #[test]
+    fn part_2() {
+        let input = "
+        467..114..
+        ...*......
+        ..35..633.
+        ......#...
+        617*......
+        .....+.58.
+        ..592.....
+        ......755.
+        ...$.*....
+        .664.598..
+        100....100
+        ..*.....*.
+        ..10....20";
+        assert_eq!(part2(input), 470835);
+    }
+
+    #[test]
+    fn edge_cases() {
+        // (schematic, part 1, part 2)
+        let corpus = [
+            // one-row grids
+            ("12*34", 46, 408),
+            ("*1.2", 1, 0),
+            ("...", 0, 0),
+            // symbols in corners
+            ("*.......#
+            .12...34.
+            .........
+            5.......6
+            $.......%", 57, 0),
+            // gears on the first row and column
+            ("*2
+            3.", 5, 6),
+            // numbers at the borders of ragged rows
+            ("467
+            ...*
+            35", 467, 0),
+            ("..........123
+            *", 0, 0),
+            ("1
+            *
+            2", 3, 2),
+            // blank rows in between are still rows
+            ("1\n\n*", 0, 0),
+            // non-square grids
+            ("1.........*
+            .........2.", 2, 0),
+            ("1
+            .
+            +
+            .
+            9", 0, 0)
+        ];
+
+        for (input, sum, ratios) in corpus {
+            assert_eq!(part1(input), sum, "{input}");
+            assert_eq!(part2(input), ratios, "{input}");
+        }
+    }
+
+    #[test]
+    fn render() {
+        let input = "467..1
+        ...*..
+        .35..#";
+        let schematic = Schematic::from(input);
+
+        assert_eq!(schematic.annotate('*', 2), vec![
+            vec![(Part, String::from("467")), (Empty, String::from("..")), (Ignored, String::from("1"))],
+            vec![(Empty, String::from("...")), (Gear(16345), String::from("*")), (Empty, String::from(".."))],
+            vec![(Empty, String::from(".")), (Part, String::from("35")), (Empty, String::from("..")), (Symbol, String::from("#"))]
+        ]);
+
+        let ansi = schematic.render_ansi('*', 2);
+        assert_eq!(ansi.lines().count(), 3);
+        assert!(ansi.lines().nth(1).unwrap().ends_with("16345\x1b[0m"));
+
+        let html = schematic.render_html('*', 2);
+        assert!(html.contains("<span class=\"gear\" title=\"ratio 16345\">*</span>"));
+        assert!(html.contains("<span class=\"ignored\">1</span>"));
+        assert!(Schematic::from("1&").render_html('*', 2).contains("&amp;"));
+
+        // numbers keep their leading zeros
+        assert_eq!(Schematic::from("007*").annotate('*', 1), vec![
+            vec![(Part, String::from("007")), (Gear(7), String::from("*"))]
+        ]);
+    }
+
+    #[test]
+    fn queries() {
+        let input = "467..114..
+        ...*......
+        ..35..633.
+        ......#...
+        617*......";
+        let schematic = Schematic::from(input);
+
+        let star = schematic.symbols[0];
+        assert_eq!(star, Symbol { row: 1, col: 3, char: '*' });
+        assert_eq!(
+            schematic.numbers_adjacent_to(&star).iter().map(|number| number.value).collect::<Vec<_>>(),
+            vec![467, 35]
+        );
+
+        let number = schematic.numbers.iter().find(|number| number.value == 633).unwrap();
+        assert_eq!(number, &Number { row: 2, start: 6, end: 9, value: 633, text: String::from("633") });
+        assert_eq!(schematic.symbols_adjacent_to(number), vec![&schematic.symbols[1]]);
+        assert!(schematic.symbols_adjacent_to(&schematic.numbers[1]).is_empty());
+
+        assert_eq!(schematic.gears('*', 2).len(), 1);
+        assert_eq!(schematic.gears('*', 1)[0].1, vec![&schematic.numbers[4]]);
+        assert!(schematic.gears('#', 2).is_empty());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,315 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    error::Error,
+    fmt,
+    io::{self, BufRead}
+};
+
+fn parse_numbers<T>(card_part: &str) -> T 
+where
+    T: FromIterator<u32> {
+    card_part
+        .split_whitespace()
+        .map(|value| value
+            .parse()
+            .expect("Error: Not a valid number.")
+        ).collect::<T>()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Card {
+    pub id: u32,
+    pub winners: Vec<u32>,
+    pub numbers: Vec<u32>
+}
+
+impl From<&str> for Card {
+    fn from(line: &str) -> Self {
+        let (name, card) = line
+            .split_once(':')
+            .expect("Error: Card without ':'.");
+        let (winners, numbers) = card
+            .split_once('|')
+            .expect("Error: Card without '|'.");
+
+        Card {
+            id: name
+                .split_whitespace()
+                .nth(1)
+                .and_then(|id| id.parse().ok())
+                .expect("Error: Not a valid card id."),
+            winners: parse_numbers(winners),
+            numbers: parse_numbers(numbers)
+        }
+    }
+}
+
+// one bit per number, None if a number does not fit into 0..128
+fn bitset<'a>(numbers: impl IntoIterator<Item = &'a u32>) -> Option<u128> {
+    numbers
+        .into_iter()
+        .try_fold(0_u128, |bits, &number| (number < 128).then(|| bits | 1 << number))
+}
+
+// how many distinct numbers both lists share
+fn count_matches(winners: &[u32], numbers: &[u32]) -> usize {
+    match (bitset(winners), bitset(numbers)) {
+        (Some(winners), Some(numbers)) => (winners & numbers).count_ones() as usize,
+        _ => {
+            let winners: HashSet<_> = winners.iter().collect();
+            let numbers: HashSet<_> = numbers.iter().collect();
+            winners.intersection(&numbers).count()
+        }
+    }
+}
+
+impl Card {
+    // how many of my numbers are winners
+    pub fn matches(&self) -> usize {
+        count_matches(&self.winners, &self.numbers)
+    }
+
+    // None if the points do not fit into 128 bits
+    pub fn points(&self) -> Option<u128> {
+        match self.matches() {
+            0 => Some(0),
+            matches => u32::try_from(matches - 1).ok().and_then(|shift| 1_u128.checked_shl(shift))
+        }
+    }
+}
+
+pub fn read_cards(input: &str) -> Vec<Card> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| Card::from(line.trim()))
+        .collect()
+}
+
+// where the copies won by a card go
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rule {
+    // the next cards, copies past the end of the table are lost
+    Capped,
+    // the next cards, continuing at the start of the table after the end
+    // cards are scratched once in order, so copies of already scratched cards win nothing
+    Wrapping
+}
+use Rule::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Copies {
+    pub id: u32,
+    // the original and every copy of the card
+    pub instances: u64,
+    // copies of other cards won by all instances of this card
+    pub won: u64
+}
+
+// scratches every card in order, None if the counts do not fit into 64 bits
+pub fn cascade(cards: &[Card], rule: Rule) -> Option<Vec<Copies>> {
+    let mut copies: Vec<Copies> = cards
+        .iter()
+        .map(|card| Copies { id: card.id, instances: 1, won: 0 })
+        .collect();
+
+    for (i, card) in cards.iter().enumerate() {
+        let instances = copies[i].instances;
+
+        let targets: Vec<usize> = match rule {
+            Capped => ((i + 1)..(i + 1 + card.matches()).min(cards.len())).collect(),
+            Wrapping => ((i + 1)..(i + 1 + card.matches())).map(|j| j % cards.len()).collect()
+        };
+
+        for j in targets {
+            copies[j].instances = copies[j].instances.checked_add(instances)?;
+            copies[i].won = copies[i].won.checked_add(instances)?;
+        }
+    }
+
+    Some(copies)
+}
+
+// the n cards that won the most copies, ties go to the lower id
+pub fn top_winners(copies: &[Copies], n: usize) -> Vec<Copies> {
+    let mut copies = copies.to_vec();
+    copies.sort_by(|a, b| b.won.cmp(&a.won).then(a.id.cmp(&b.id)));
+    copies.truncate(n);
+    copies
+}
+
+pub fn total_instances(copies: &[Copies]) -> Option<u64> {
+    copies.iter().try_fold(0_u64, |sum, copies| sum.checked_add(copies.instances))
+}
+
+#[derive(Debug)]
+pub enum ScratchcardError {
+    Io(io::Error),
+    Overflow
+}
+
+impl fmt::Display for ScratchcardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScratchcardError::Io(error) => write!(f, "Error: Could not read the cards ({error})."),
+            ScratchcardError::Overflow => write!(f, "Error: The scratchcard count does not fit into 64 bits.")
+        }
+    }
+}
+
+impl Error for ScratchcardError {}
+
+impl From<io::Error> for ScratchcardError {
+    fn from(error: io::Error) -> Self {
+        ScratchcardError::Io(error)
+    }
+}
+
+// counts the scratchcards of a pile read line by line with the capped rule
+// only the copies won for the next cards are kept, so memory grows with the most matches on a card
+pub fn count_scratchcards<R: BufRead>(mut reader: R) -> Result<u64, ScratchcardError> {
+    let mut line = String::new();
+    // pending[k] holds the copies won for the card k positions ahead
+    let mut pending: VecDeque<u64> = VecDeque::new();
+    let mut total: u64 = 0;
+
+    while {
+        line.clear();
+        reader.read_line(&mut line)? > 0
+    } {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let card = Card::from(line.trim());
+        let instances = pending
+            .pop_front()
+            .unwrap_or(0)
+            .checked_add(1)
+            .ok_or(ScratchcardError::Overflow)?;
+        total = total.checked_add(instances).ok_or(ScratchcardError::Overflow)?;
+
+        let matches = card.matches();
+        if pending.len() < matches {
+            pending.resize(matches, 0);
+        }
+        for copies in pending.iter_mut().take(matches) {
+            *copies = copies.checked_add(instances).ok_or(ScratchcardError::Overflow)?;
+        }
+    }
+
+    Ok(total)
+}
+
+pub fn part1(input: &str) -> u128 {
+    read_cards(input)
+        .iter()
+        .try_fold(0_u128, |total, card| total.checked_add(card.points()?))
+        .expect("Error: The points do not fit into 128 bits.")
+}
+
+pub fn part2(input: &str) -> u64 {
+    count_scratchcards(input.as_bytes()).unwrap_or_else(|error| panic!("{error}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+        Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+        Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+        Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+        Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+        Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        assert_eq!(part1(input), 13);
+    }
+
+    #[test]
+    fn points() {
+        let card = |matches: u32| {
+            let numbers: Vec<String> = (1..=matches).map(|n| n.to_string()).collect();
+            format!("Card 1: {} | {}", numbers.join(" "), numbers.join(" "))
+        };
+
+        assert_eq!(part1(&card(40)), 1 << 39);
+        assert_eq!(part1(&card(128)), 1 << 127);
+        assert!(Card::from(card(129).as_str()).points().is_none());
+    }
+
+    #[test]
+    fn part_2() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+        Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+        Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+        Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+        Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+        Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        assert_eq!(part2(input), 30);
+    }
+
+    #[test]
+    fn cascades() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+        Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+        Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+        Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+        Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+        Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        let cards = read_cards(input);
+        assert_eq!(cards[2], Card { id: 3, winners: vec![1, 21, 53, 59, 44], numbers: vec![69, 82, 63, 72, 16, 21, 14, 1] });
+
+        let copies = cascade(&cards, Capped).unwrap();
+        assert_eq!(copies.iter().map(|copies| copies.instances).collect::<Vec<_>>(), vec![1, 2, 4, 8, 14, 1]);
+        assert_eq!(
+            top_winners(&copies, 2),
+            vec![Copies { id: 3, instances: 4, won: 8 }, Copies { id: 4, instances: 8, won: 8 }]
+        );
+
+        // card 5 and 6 win nothing, so wrapping only matters for a table that ends on a winner
+        let copies = cascade(&cards[..4], Wrapping).unwrap();
+        assert_eq!(copies.iter().map(|copies| copies.instances).collect::<Vec<_>>(), vec![14, 2, 4, 8]);
+        assert_eq!(total_instances(&copies), Some(28));
+
+        // every card wins a copy of every later card, so the counts double each card
+        let numbers: Vec<u32> = (1..=70).collect();
+        let cards: Vec<Card> = (1..=70)
+            .map(|id| Card { id, winners: numbers.clone(), numbers: numbers.clone() })
+            .collect();
+        assert_eq!(cascade(&cards[..64], Capped).unwrap()[63].instances, 1 << 63);
+        assert_eq!(cascade(&cards, Capped), None);
+    }
+
+    #[test]
+    fn stream() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+        Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+        Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+        Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+        Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+        Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        let cards = read_cards(input);
+        let expected = total_instances(&cascade(&cards, Capped).unwrap());
+        assert_eq!(count_scratchcards(input.as_bytes()).ok(), expected);
+
+        // numbers past the bitset fall back to hashing
+        assert_eq!(Card::from("Card 1: 127 128 1000 | 1000 127 5").matches(), 2);
+
+        // repeated numbers match once on both paths
+        assert_eq!(Card::from("Card 1: 5 | 5 5").matches(), 1);
+        assert_eq!(Card::from("Card 1: 5 200 | 5 5 200 200").matches(), 2);
+        assert_eq!(Card::from("Card 1: 5 5 200 200 | 5 200").matches(), 2);
+        assert_eq!(bitset(&[0, 127]), Some(1 << 127 | 1));
+        assert_eq!(bitset(&[128]), None);
+
+        // every card wins one copy of the next
+        let pile = "Card 1: 1 | 1\n".repeat(100_000);
+        assert_eq!(count_scratchcards(pile.as_bytes()).unwrap(), 100_000 * 100_001 / 2);
+
+        // winning two copies grows the counts like the fibonacci numbers
+        let pile = "Card 1: 1 2 | 1 2\n".repeat(100);
+        assert!(matches!(count_scratchcards(pile.as_bytes()), Err(ScratchcardError::Overflow)));
+    }
+}
@@ -0,0 +1,764 @@
+use std::{collections::{HashMap, VecDeque}, fmt, ops::Range};
+
+// splits a "seed-to-soil map:" header into its categories
+fn parse_header(line: &str) -> Option<(String, String)> {
+    line
+        .strip_suffix("map:")
+        .and_then(|name| name.trim().split_once("-to-"))
+        .filter(|(source, destination)| !source.is_empty() && !destination.is_empty())
+        .map(|(source, destination)| (source.to_string(), destination.to_string()))
+}
+
+fn read_header(line: &str) -> (String, String) {
+    parse_header(line).unwrap_or_else(|| panic!("Error: Invalid map header '{line}'."))
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Problem {
+    OddSeeds(usize),
+    UnknownHeader(String),
+    InvalidRow(String),
+    RowBeforeHeader,
+    EmptyRange(i64),
+    // the source range overlaps the one on the given line
+    Overlap(usize)
+}
+use Problem::*;
+
+// a problem found on a line of the almanac, lines count from 1
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Issue {
+    pub line: usize,
+    pub problem: Problem
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.problem {
+            OddSeeds(count) => write!(f, "{count} seeds cannot be read as pairs of start and length"),
+            UnknownHeader(line) => write!(f, "unknown header '{line}'"),
+            InvalidRow(line) => write!(f, "invalid map row '{line}'"),
+            RowBeforeHeader => write!(f, "map row before the first header"),
+            EmptyRange(length) => write!(f, "range of length {length} maps nothing"),
+            Overlap(other) => write!(f, "source range overlaps the one on line {other}")
+        }
+    }
+}
+
+// checks the almanac for everything the solvers silently tolerate
+// source ranges are half-open, so [source, source + length) may not meet another row of the same map
+pub fn validate(input: &str) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let mut lines = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty());
+
+    match lines.next() {
+        Some((line, seeds)) => match seeds.strip_prefix("seeds:") {
+            Some(seeds) => {
+                let count = seeds.split_whitespace().count();
+                if seeds.split_whitespace().any(|seed| seed.parse::<i64>().is_err()) {
+                    issues.push(Issue { line, problem: InvalidRow(seeds.trim().to_string()) });
+                } else if count % 2 == 1 {
+                    issues.push(Issue { line, problem: OddSeeds(count) });
+                }
+            },
+            None => issues.push(Issue { line, problem: UnknownHeader(seeds.to_string()) })
+        },
+        None => return issues
+    }
+
+    // the source ranges of the current map with their lines
+    let mut sources: Option<Vec<(usize, Range<i64>)>> = None;
+    for (line, text) in lines {
+        let numbers: Vec<&str> = text.split_whitespace().collect();
+
+        if numbers.len() != 3 || numbers[0].parse::<i64>().is_err() {
+            if parse_header(text).is_some() {
+                sources = Some(Vec::new());
+            } else if numbers.iter().all(|number| number.parse::<i64>().is_ok()) {
+                issues.push(Issue { line, problem: InvalidRow(text.to_string()) });
+            } else {
+                issues.push(Issue { line, problem: UnknownHeader(text.to_string()) });
+                sources = Some(Vec::new());
+            }
+            continue;
+        }
+
+        let Ok(row) = numbers.iter().map(|number| number.parse::<i64>()).collect::<Result<Vec<_>, _>>() else {
+            issues.push(Issue { line, problem: InvalidRow(text.to_string()) });
+            continue;
+        };
+
+        let Some(sources) = sources.as_mut() else {
+            issues.push(Issue { line, problem: RowBeforeHeader });
+            continue;
+        };
+
+        if row[2] <= 0 {
+            issues.push(Issue { line, problem: EmptyRange(row[2]) });
+            continue;
+        }
+
+        let source = row[1]..row[1] + row[2];
+        for (other, range) in sources.iter() {
+            if source.start < range.end && range.start < source.end {
+                issues.push(Issue { line, problem: Overlap(*other) });
+            }
+        }
+        sources.push((line, source));
+    }
+
+    issues
+}
+
+pub fn read_almanac(input: &str) -> (Vec<i64>, Vec<Map>) {
+    let mut input = input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.trim());
+
+    let seeds: Vec<i64> = input
+        .next()
+        .unwrap()
+        .split(':')
+        .nth(1)
+        .unwrap()
+        .split_whitespace()
+        .map(|seed| seed.parse::<i64>().unwrap())
+        .collect();
+
+    // the categories and rows of every map
+    let mut blocks: Vec<(String, String, Vec<Vec<i64>>)> = Vec::with_capacity(7);
+    for line in input {
+
+        let numbers: Vec<&str> = line
+            .split_whitespace()
+            .collect();
+
+        if numbers.len() == 3 {
+            let numbers: Vec<i64> = numbers
+                .iter()
+                .map(|num| num.parse().unwrap())
+                .collect();
+
+            blocks
+                .last_mut()
+                .expect("Error: Map row before the first header.")
+                .2
+                .push(numbers);
+
+        } else {
+            let (source, destination) = read_header(line);
+            blocks.push((source, destination, Vec::new()));
+        }
+    }
+
+    let maps = blocks
+        .iter()
+        .map(|(source, destination, ranges)| Map::new(source, destination, ranges))
+        .collect();
+
+    (seeds, maps)
+}
+
+// joins touching pieces with the same offset
+fn merged(mut pieces: Vec<Piece>) -> Vec<Piece> {
+    pieces.sort_by_key(|piece| piece.start);
+
+    let mut merged: Vec<Piece> = Vec::with_capacity(pieces.len());
+    for piece in pieces.into_iter().filter(|piece| piece.start < piece.end) {
+        match merged.last_mut() {
+            Some(last) if last.end == piece.start && last.offset == piece.offset => last.end = piece.end,
+            _ => merged.push(piece)
+        }
+    }
+    merged
+}
+
+// sorts the ranges and joins the overlapping or touching ones
+fn merge_ranges(mut ranges: Vec<Range<i64>>) -> Vec<Range<i64>> {
+    ranges.retain(|range| !range.is_empty());
+    ranges.sort_by_key(|range| range.start);
+
+    let mut merged: Vec<Range<i64>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range)
+        }
+    }
+    merged
+}
+
+// values in [start, end) are shifted by offset
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Piece {
+    pub start: i64,
+    pub end: i64,
+    pub offset: i64
+}
+
+// a piecewise-linear map between two categories, the pieces are sorted and cover [i64::MIN, i64::MAX)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Map {
+    pub source: String,
+    pub destination: String,
+    pub pieces: Vec<Piece>
+}
+
+impl Map {
+    pub fn identity(category: &str) -> Self {
+        Map {
+            source: category.to_string(),
+            destination: category.to_string(),
+            pieces: vec![Piece { start: i64::MIN, end: i64::MAX, offset: 0 }]
+        }
+    }
+
+    // builds a map from [destination, source, length] rows covering [source, source + length)
+    // the first row covering a value wins, validate reports such overlaps
+    pub fn new(source: &str, destination: &str, ranges: &[Vec<i64>]) -> Self {
+        let mut mapped: Vec<Piece> = Vec::new();
+
+        // rows without a length map nothing
+        for range in ranges.iter().filter(|range| range[2] > 0) {
+            // the parts of the source range no earlier row covers yet
+            let mut uncovered = vec![(range[1], range[1] + range[2])];
+            for piece in mapped.iter() {
+                uncovered = uncovered
+                    .into_iter()
+                    .flat_map(|(start, end)| [(start, end.min(piece.start)), (start.max(piece.end), end)])
+                    .filter(|(start, end)| start < end)
+                    .collect();
+            }
+
+            mapped.extend(uncovered
+                .into_iter()
+                .map(|(start, end)| Piece { start, end, offset: range[0] - range[1] }));
+        }
+
+        // everything else maps to itself
+        mapped.sort_by_key(|piece| piece.start);
+        let mut pieces = Vec::with_capacity(2 * mapped.len() + 1);
+        let mut start = i64::MIN;
+        for piece in mapped {
+            if start < piece.start {
+                pieces.push(Piece { start, end: piece.start, offset: 0 });
+            }
+            start = piece.end;
+            pieces.push(piece);
+        }
+        pieces.push(Piece { start, end: i64::MAX, offset: 0 });
+
+        Map {
+            source: source.to_string(),
+            destination: destination.to_string(),
+            pieces: merged(pieces)
+        }
+    }
+
+    fn piece(&self, value: i64) -> Option<&Piece> {
+        let i = self.pieces.partition_point(|piece| piece.end <= value);
+        self.pieces.get(i).filter(|piece| piece.start <= value)
+    }
+
+    pub fn apply(&self, value: i64) -> i64 {
+        self.piece(value).map_or(value, |piece| value + piece.offset)
+    }
+
+    // the map applying self and then next
+    pub fn then(&self, next: &Map) -> Map {
+        let mut pieces = Vec::new();
+
+        for piece in self.pieces.iter() {
+            let (start, end) = (piece.start + piece.offset, piece.end + piece.offset);
+
+            for other in next.pieces.iter().filter(|other| other.start < end && start < other.end) {
+                pieces.push(Piece {
+                    start: start.max(other.start) - piece.offset,
+                    end: end.min(other.end) - piece.offset,
+                    offset: piece.offset + other.offset
+                });
+            }
+        }
+
+        Map {
+            source: self.source.clone(),
+            destination: next.destination.clone(),
+            pieces: merged(pieces)
+        }
+    }
+
+    // the sorted ranges a range is mapped to
+    pub fn image(&self, range: &Range<i64>) -> Vec<Range<i64>> {
+        merge_ranges(self.pieces
+            .iter()
+            .map(|piece| range.start.max(piece.start) + piece.offset..range.end.min(piece.end) + piece.offset)
+            .collect())
+    }
+
+    // the sorted ranges mapped into a range, several since unmapped values map to themselves
+    pub fn preimage(&self, range: &Range<i64>) -> Vec<Range<i64>> {
+        merge_ranges(self.pieces
+            .iter()
+            .map(|piece| {
+                let start = range.start.max(piece.start + piece.offset) - piece.offset;
+                let end = range.end.min(piece.end + piece.offset) - piece.offset;
+                start..end
+            })
+            .collect())
+    }
+
+    // the smallest value a range is mapped to
+    pub fn min_over(&self, range: &Range<i64>) -> Option<i64> {
+        self.pieces
+            .iter()
+            .filter(|piece| piece.start < range.end && range.start < piece.end)
+            .map(|piece| range.start.max(piece.start) + piece.offset)
+            .min()
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} to {}", self.source, self.destination)?;
+        writeln!(f, "{:>15} {:>15} {:>15} {:>15} {:>15}", "from", "to", "mapped from", "mapped to", "offset")?;
+        for piece in self.pieces.iter().filter(|piece| piece.offset != 0) {
+            writeln!(
+                f,
+                "{:>15} {:>15} {:>15} {:>15} {:>+15}",
+                piece.start,
+                piece.end,
+                piece.start + piece.offset,
+                piece.end + piece.offset,
+                piece.offset
+            )?;
+        }
+        write!(f, "every other value maps to itself")
+    }
+}
+
+// the maps form a directed graph of categories
+#[derive(Clone, Debug)]
+pub struct Almanac {
+    pub seeds: Vec<i64>,
+    pub maps: Vec<Map>,
+    // the maps from seed to location composed into one, None if no maps lead there
+    pub composed: Option<Map>
+}
+
+impl From<&str> for Almanac {
+    fn from(input: &str) -> Self {
+        let (seeds, maps) = read_almanac(input);
+        let mut almanac = Almanac {
+            seeds,
+            maps,
+            composed: None
+        };
+        almanac.composed = almanac.convert("seed", "location");
+
+        almanac
+    }
+}
+
+impl Almanac {
+    pub fn categories(&self) -> Vec<&str> {
+        let mut categories: Vec<&str> = self.maps
+            .iter()
+            .flat_map(|map| [map.source.as_str(), map.destination.as_str()])
+            .collect();
+        categories.sort();
+        categories.dedup();
+        categories
+    }
+
+    // the shortest chain of maps leading from one category to another
+    pub fn path(&self, from: &str, to: &str) -> Option<Vec<&Map>> {
+        let mut previous: HashMap<&str, &Map> = HashMap::new();
+        let mut queue = VecDeque::from([from]);
+
+        while let Some(category) = queue.pop_front() {
+            if category == to {
+                let mut path = Vec::new();
+                let mut category = to;
+                while category != from {
+                    let map = previous[category];
+                    path.push(map);
+                    category = &map.source;
+                }
+                path.reverse();
+                return Some(path);
+            }
+
+            for map in self.maps.iter().filter(|map| map.source == category) {
+                if map.destination != from && !previous.contains_key(map.destination.as_str()) {
+                    previous.insert(&map.destination, map);
+                    queue.push_back(&map.destination);
+                }
+            }
+        }
+
+        None
+    }
+
+    // the maps along the path from one category to another composed into one
+    pub fn convert(&self, from: &str, to: &str) -> Option<Map> {
+        Some(self
+            .path(from, to)?
+            .into_iter()
+            .fold(Map::identity(from), |composed, map| composed.then(map)))
+    }
+
+    // the seeds read as pairs of start and length
+    pub fn seed_ranges(&self) -> Vec<Range<i64>> {
+        self.seeds
+            .chunks(2)
+            .map(|pair| pair[0]..pair[0] + pair.get(1).copied().unwrap_or(0))
+            .collect()
+    }
+
+    // the location methods return None if no maps lead from seed to location
+    pub fn location(&self, seed: i64) -> Option<i64> {
+        Some(self.composed.as_ref()?.apply(seed))
+    }
+
+    // also None if the seed ranges are empty
+    pub fn min_location(&self, seed_ranges: &[Range<i64>]) -> Option<i64> {
+        let composed = self.composed.as_ref()?;
+
+        seed_ranges
+            .iter()
+            .filter_map(|range| composed.min_over(range))
+            .min()
+    }
+
+    // the sorted seed ranges whose locations fall into location_range
+    pub fn ranges_mapping_to(&self, location_range: Range<i64>) -> Option<Vec<Range<i64>>> {
+        Some(self.composed.as_ref()?.preimage(&location_range))
+    }
+
+    // the sorted ranges of from values that end up in range, inverting one map after another
+    pub fn inverse(&self, from: &str, to: &str, range: Range<i64>) -> Option<Vec<Range<i64>>> {
+        Some(self
+            .path(from, to)?
+            .iter()
+            .rev()
+            .fold(vec![range], |ranges, map| merge_ranges(ranges
+                .iter()
+                .flat_map(|range| map.preimage(range))
+                .collect())))
+    }
+}
+
+pub fn part1(input: &str) -> i64 {
+    let almanac = Almanac::from(input);
+
+    almanac.seeds
+        .iter()
+        .map(|seed| almanac.location(*seed).expect("Error: No maps from seed to location."))
+        .min()
+        .expect("Error: No seeds found.")
+}
+
+pub fn part2(input: &str) -> i64 {
+    let almanac = Almanac::from(input);
+    assert!(almanac.composed.is_some(), "Error: No maps from seed to location.");
+
+    almanac
+        .min_location(&almanac.seed_ranges())
+        .expect("Error: No seeds found.")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1() {
+        let input = "seeds: 79 14 55 13
+        seed-to-soil map:
+        50 98 2
+        52 50 48
+        soil-to-fertilizer map:
+        0 15 37
+        37 52 2
+        39 0 15
+        fertilizer-to-water map:
+        49 53 8
+        0 11 42
+        42 0 7
+        57 7 4
+        water-to-light map:
+        88 18 7
+        18 25 70
+        light-to-temperature map:
+        45 77 23
+        81 45 19
+        68 64 13
+        temperature-to-humidity map:
+        0 69 1
+        1 0 69
+        humidity-to-location map:
+        60 56 37
+        56 93 4";
+        assert_eq!(part1(input), 35);
+    }
+
+    #[test]
+    fn part_2() {
+        let input = "seeds: 79 14 55 13
+        seed-to-soil map:
+        50 98 2
+        52 50 48
+        soil-to-fertilizer map:
+        0 15 37
+        37 52 2
+        39 0 15
+        fertilizer-to-water map:
+        49 53 8
+        0 11 42
+        42 0 7
+        57 7 4
+        water-to-light map:
+        88 18 7
+        18 25 70
+        light-to-temperature map:
+        45 77 23
+        81 45 19
+        68 64 13
+        temperature-to-humidity map:
+        0 69 1
+        1 0 69
+        humidity-to-location map:
+        60 56 37
+        56 93 4";
+        assert_eq!(part2(input), 46);
+    }
+
+    #[test]
+    fn almanac() {
+        let input = "seeds: 79 14 55 13
+        seed-to-soil map:
+        50 98 2
+        52 50 48
+        soil-to-fertilizer map:
+        0 15 37
+        37 52 2
+        39 0 15
+        fertilizer-to-water map:
+        49 53 8
+        0 11 42
+        42 0 7
+        57 7 4
+        water-to-light map:
+        88 18 7
+        18 25 70
+        light-to-temperature map:
+        45 77 23
+        81 45 19
+        68 64 13
+        temperature-to-humidity map:
+        0 69 1
+        1 0 69
+        humidity-to-location map:
+        60 56 37
+        56 93 4";
+        let almanac = Almanac::from(input);
+
+        // the composed map agrees with applying the maps one after another
+        for seed in -10..200 {
+            let location = almanac.maps.iter().fold(seed, |value, map| map.apply(value));
+            assert_eq!(almanac.location(seed), Some(location));
+        }
+
+        assert_eq!(almanac.location(79), Some(82));
+        assert_eq!(almanac.seed_ranges(), vec![79..93, 55..68]);
+        assert_eq!(almanac.min_location(&almanac.seed_ranges()), Some(46));
+        assert_eq!(almanac.min_location(&[]), None);
+
+        let ranges = almanac.ranges_mapping_to(46..47).unwrap();
+        assert!(ranges.iter().any(|range| range.contains(&82)));
+        for range in ranges {
+            assert!(range.clone().all(|seed| almanac.location(seed) == Some(46)));
+        }
+
+        assert!(almanac.composed.as_ref().unwrap().to_string().starts_with("seed to location\n"));
+        let map = Map::new("a", "b", &[vec![10, 0, 5], vec![20, 3, 5]]);
+        assert_eq!(map.apply(4), 14);
+        assert_eq!(map.apply(5), 22);
+    }
+
+    #[test]
+    fn categories() {
+        // the blocks are shuffled
+        let input = "seeds: 79 14 55 13
+        humidity-to-location map:
+        60 56 37
+        56 93 4
+        water-to-light map:
+        88 18 7
+        18 25 70
+        seed-to-soil map:
+        50 98 2
+        52 50 48
+        temperature-to-humidity map:
+        0 69 1
+        1 0 69
+        fertilizer-to-water map:
+        49 53 8
+        0 11 42
+        42 0 7
+        57 7 4
+        soil-to-fertilizer map:
+        0 15 37
+        37 52 2
+        39 0 15
+        light-to-temperature map:
+        45 77 23
+        81 45 19
+        68 64 13";
+        assert_eq!(part1(input), 35);
+        assert_eq!(part2(input), 46);
+
+        let almanac = Almanac::from(input);
+        assert_eq!(almanac.categories().len(), 8);
+
+        let path: Vec<&str> = almanac
+            .path("soil", "humidity")
+            .unwrap()
+            .iter()
+            .map(|map| map.destination.as_str())
+            .collect();
+        assert_eq!(path, vec!["fertilizer", "water", "light", "temperature", "humidity"]);
+
+        // soil 81 is fertilizer 81, water 81, light 74, temperature 78 and humidity 78
+        let soil_to_humidity = almanac.convert("soil", "humidity").unwrap();
+        assert_eq!((soil_to_humidity.source.as_str(), soil_to_humidity.destination.as_str()), ("soil", "humidity"));
+        assert_eq!(soil_to_humidity.apply(81), 78);
+        assert_eq!(almanac.convert("light", "light").unwrap().apply(5), 5);
+        assert!(almanac.convert("location", "seed").is_none());
+
+        // without a chain to location the other conversions still work
+        let almanac = Almanac::from("seeds: 1
+        seed-to-soil map:
+        50 0 2");
+        assert!(almanac.composed.is_none());
+        assert_eq!((almanac.location(1), almanac.min_location(&almanac.seed_ranges())), (None, None));
+        assert_eq!(almanac.convert("seed", "soil").unwrap().apply(1), 51);
+    }
+
+    #[test]
+    fn empty_rows() {
+        let map = Map::new("a", "b", &[vec![100, 10, -5], vec![200, 50, 5]]);
+
+        assert!(map.pieces.windows(2).all(|pair| pair[0].end == pair[1].start));
+        assert!(map.pieces.iter().all(|piece| piece.start < piece.end));
+        assert_eq!((map.apply(8), map.apply(10), map.apply(50), map.apply(54), map.apply(55)), (8, 10, 200, 204, 55));
+    }
+
+    #[test]
+    fn inverse() {
+        let input = "seeds: 79 14 55 13
+        seed-to-soil map:
+        50 98 2
+        52 50 48
+        soil-to-fertilizer map:
+        0 15 37
+        37 52 2
+        39 0 15
+        fertilizer-to-water map:
+        49 53 8
+        0 11 42
+        42 0 7
+        57 7 4
+        water-to-light map:
+        88 18 7
+        18 25 70
+        light-to-temperature map:
+        45 77 23
+        81 45 19
+        68 64 13
+        temperature-to-humidity map:
+        0 69 1
+        1 0 69
+        humidity-to-location map:
+        60 56 37
+        56 93 4";
+        let almanac = Almanac::from(input);
+
+        // the example maps are permutations, so mapping the preimage gives back the range
+        for range in [0..1, 46..47, 10..60, 90..120, -5..5] {
+            for map in almanac.maps.iter().chain(&almanac.composed) {
+                let image = map.preimage(&range).iter().flat_map(|range| map.image(range)).collect();
+                assert_eq!(merge_ranges(image), vec![range.clone()]);
+            }
+
+            let seeds = almanac.inverse("seed", "location", range.clone()).unwrap();
+            assert_eq!(Some(&seeds), almanac.ranges_mapping_to(range.clone()).as_ref());
+            let locations = seeds.iter().flat_map(|seeds| almanac.composed.as_ref().unwrap().image(seeds)).collect();
+            assert_eq!(merge_ranges(locations), vec![range]);
+        }
+
+        let seeds = almanac.inverse("seed", "location", 46..47).unwrap();
+        assert_eq!((seeds.len(), &seeds[0]), (1, &(82..83)));
+        let soil = almanac.inverse("soil", "humidity", 78..79).unwrap();
+        assert_eq!((soil.len(), &soil[0]), (1, &(81..82)));
+        assert_eq!(almanac.inverse("humidity", "seed", 0..1), None);
+
+        // two rows onto the same values give two preimages, values nothing maps to give none
+        let map = Map::new("a", "b", &[vec![0, 10, 5], vec![0, 20, 5]]);
+        assert_eq!(map.preimage(&(2..3)), vec![2..3, 12..13, 22..23]);
+        assert_eq!(map.preimage(&(12..13)), vec![]);
+        for seed in [2, 12, 22] {
+            assert_eq!(map.apply(seed), 2);
+        }
+    }
+
+    #[test]
+    fn validation() {
+        let input = "seeds: 79 14 55 13
+        seed-to-soil map:
+        50 98 2
+        52 50 48
+        soil-to-fertilizer map:
+        0 15 37
+        37 52 2
+        39 0 15";
+        assert_eq!(validate(input), vec![]);
+
+        let input = "seeds: 79 14 55
+
+        seed-to-soil map:
+        50 98 2
+        52 50 49
+        10 20 0
+        soil to fertilizer:
+        0 15 37
+        37 50 3
+        1 2
+        fertilizer-to-water map:
+        49 53 8";
+        let issues = validate(input);
+        assert_eq!(issues, vec![
+            Issue { line: 1, problem: OddSeeds(3) },
+            Issue { line: 5, problem: Overlap(4) },
+            Issue { line: 6, problem: EmptyRange(0) },
+            Issue { line: 7, problem: UnknownHeader(String::from("soil to fertilizer:")) },
+            Issue { line: 9, problem: Overlap(8) },
+            Issue { line: 10, problem: InvalidRow(String::from("1 2")) }
+        ]);
+        assert_eq!(issues[1].to_string(), "line 5: source range overlaps the one on line 4");
+
+        // ranges ending right where the next one starts do not overlap
+        assert_eq!(validate("seeds: 1 2\na-to-b map:\n0 10 5\n20 15 5"), vec![]);
+        assert_eq!(validate("seeds: 1 2\n0 10 5"), vec![Issue { line: 2, problem: RowBeforeHeader }]);
+
+        // the range ends are exclusive, so 98 + 2 stays unmapped
+        let map = Map::new("seed", "soil", &[vec![50, 98, 2]]);
+        assert_eq!((map.apply(99), map.apply(100)), (51, 100));
+    }
+}
@@ -0,0 +1,317 @@
+use std::ops::RangeInclusive;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Race {
+    pub time: u128,
+    pub distance: u128
+}
+
+// the values after the ':' of every line
+fn read_lines(input: &str) -> Vec<&str> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line
+            .trim()
+            .split(':')
+            .nth(1)
+            .expect("Error: Line without ':'.")
+        ).collect()
+}
+
+// every column is a race
+pub fn read_races(input: &str) -> Vec<Race> {
+    let input: Vec<Vec<u128>> = read_lines(input)
+        .iter()
+        .map(|line| line
+            .split_whitespace()
+            .map(|value| value.parse().expect("Error: Not a valid number."))
+            .collect()
+        ).collect();
+
+    input[0]
+        .iter()
+        .zip(input[1].iter())
+        .map(|(&time, &distance)| Race { time, distance })
+        .collect()
+}
+
+// the columns are one race with bad kerning
+pub fn read_race(input: &str) -> Race {
+    let input: Vec<u128> = read_lines(input)
+        .iter()
+        .map(|line| line
+            .replace(' ', "")
+            .parse()
+            .expect("Error: Not a valid number.")
+        ).collect();
+
+    Race {
+        time: input[0],
+        distance: input[1]
+    }
+}
+
+impl Race {
+    // whether holding the button for hold beats the record, (time - hold) * hold > distance without overflowing
+    pub fn wins(&self, hold: u128) -> bool {
+        0 < hold && hold < self.time && self.time - hold > self.distance / hold
+    }
+
+    // the first winning hold time at most time / 2 by bisection, for races too long to square
+    fn first_win_bisected(&self) -> u128 {
+        let (mut left, mut right) = (0, self.time / 2);
+        while left < right {
+            let mid = left + (right - left) / 2;
+            if self.wins(mid) {
+                right = mid;
+            } else {
+                left = mid + 1;
+            }
+        }
+        left
+    }
+
+    // the winning hold times, they lie symmetric around time / 2
+    pub fn winning_holds(&self) -> Option<RangeInclusive<u128>> {
+        // the distance is largest at time / 2
+        if !self.wins(self.time / 2) {
+            return None;
+        }
+
+        // the smaller root of hold² - time * hold + distance = 0 is (time - √(time² - 4 * distance)) / 2
+        let discriminant = self.time
+            .checked_mul(self.time)
+            .and_then(|square| self.distance.checked_mul(4).and_then(|distance| square.checked_sub(distance)));
+
+        let first = match discriminant {
+            Some(discriminant) => {
+                // the integer square root can be off the real root by one in either direction
+                let mut first = (self.time - discriminant.isqrt()) / 2;
+                while !self.wins(first) {
+                    first += 1;
+                }
+                while first > 1 && self.wins(first - 1) {
+                    first -= 1;
+                }
+                first
+            },
+            None => self.first_win_bisected()
+        };
+
+        Some(first..=self.time - first)
+    }
+
+    pub fn ways_to_win(&self) -> u128 {
+        self.winning_holds().map_or(0, |holds| holds.end() - holds.start() + 1)
+    }
+}
+
+// how far a boat gets in a race, the distance has to rise and then fall with the hold time
+pub trait BoatModel {
+    fn distance(&self, time: u128, hold: u128) -> f64;
+
+    fn winning_holds(&self, race: &Race) -> Option<RangeInclusive<u128>> {
+        solve(self, race)
+    }
+}
+
+// the boat speeds up by one per millisecond held
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Linear;
+
+// the boat speeds up by factor per millisecond held
+#[derive(Clone, Copy, Debug)]
+pub struct Accelerated {
+    pub factor: f64
+}
+
+// the boat speeds up by factor per millisecond held, but not past top_speed
+#[derive(Clone, Copy, Debug)]
+pub struct TopSpeed {
+    pub factor: f64,
+    pub top_speed: f64
+}
+
+// the boat speeds up by factor per millisecond held, once released it slows down by drag times its speed
+#[derive(Clone, Copy, Debug)]
+pub struct Drag {
+    pub factor: f64,
+    pub drag: f64
+}
+
+impl BoatModel for Linear {
+    fn distance(&self, time: u128, hold: u128) -> f64 {
+        hold as f64 * time.saturating_sub(hold) as f64
+    }
+
+    // the exact solver needs no floats
+    fn winning_holds(&self, race: &Race) -> Option<RangeInclusive<u128>> {
+        race.winning_holds()
+    }
+}
+
+impl BoatModel for Accelerated {
+    fn distance(&self, time: u128, hold: u128) -> f64 {
+        self.factor * hold as f64 * time.saturating_sub(hold) as f64
+    }
+}
+
+impl BoatModel for TopSpeed {
+    fn distance(&self, time: u128, hold: u128) -> f64 {
+        (self.factor * hold as f64).min(self.top_speed) * time.saturating_sub(hold) as f64
+    }
+}
+
+impl BoatModel for Drag {
+    // the speed decays as v * e^(-drag * t), which integrates to v * (1 - e^(-drag * t)) / drag
+    fn distance(&self, time: u128, hold: u128) -> f64 {
+        let speed = self.factor * hold as f64;
+        let moving = time.saturating_sub(hold) as f64;
+        if self.drag == 0.0 {
+            speed * moving
+        } else {
+            speed * -(-self.drag * moving).exp_m1() / self.drag
+        }
+    }
+}
+
+// finds the best hold time by ternary search and the edges of the winning holds around it by bisection
+fn solve<M: BoatModel + ?Sized>(model: &M, race: &Race) -> Option<RangeInclusive<u128>> {
+    let distance = |hold| model.distance(race.time, hold);
+    let wins = |hold| distance(hold) > race.distance as f64;
+
+    let (mut left, mut right) = (0, race.time);
+    while right - left > 2 {
+        let third = (right - left) / 3;
+        if distance(left + third) < distance(right - third) {
+            left += third + 1;
+        } else {
+            right -= third;
+        }
+    }
+    let best = (left..=right).max_by(|a, b| distance(*a).total_cmp(&distance(*b)))?;
+    if !wins(best) {
+        return None;
+    }
+
+    // the first win before the best hold
+    let (mut left, mut right) = (0, best);
+    while left < right {
+        let mid = left + (right - left) / 2;
+        if wins(mid) {
+            right = mid;
+        } else {
+            left = mid + 1;
+        }
+    }
+    let first = left;
+
+    // the last win after the best hold
+    let (mut left, mut right) = (best, race.time);
+    while left < right {
+        let mid = right - (right - left) / 2;
+        if wins(mid) {
+            left = mid;
+        } else {
+            right = mid - 1;
+        }
+    }
+
+    Some(first..=left)
+}
+
+// one row per race with its winning hold times under a model
+pub fn table(races: &[Race], model: &dyn BoatModel) -> String {
+    let mut table = format!("{:>5} {:>15} {:>15} {:>15} {:>15} {:>15}", "race", "time", "record", "first", "last", "ways");
+
+    for (i, race) in races.iter().enumerate() {
+        table.push_str(&format!("\n{:>5} {:>15} {:>15} ", i + 1, race.time, race.distance));
+        table.push_str(&match model.winning_holds(race) {
+            Some(holds) => format!("{:>15} {:>15} {:>15}", holds.start(), holds.end(), holds.end() - holds.start() + 1),
+            None => format!("{:>15} {:>15} {:>15}", "-", "-", 0)
+        });
+    }
+
+    table
+}
+
+pub fn part1(input: &str) -> u128 {
+    read_races(input)
+        .iter()
+        .map(|race| race.ways_to_win())
+        .product()
+}
+
+pub fn part2(input: &str) -> u128 {
+    read_race(input).ways_to_win()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1() {
+        let input = "Time:      7  15   30
+        Distance:  9  40  200";
+        assert_eq!(part1(input), 288);
+    }
+
+    #[test]
+    fn part_2() {
+        let input = "Time:      7  15   30
+        Distance:  9  40  200";
+        assert_eq!(part2(input), 71503);
+    }
+
+    #[test]
+    fn exact() {
+        assert_eq!(Race { time: 30, distance: 200 }.winning_holds(), Some(11..=19));
+        // hitting the record exactly does not win
+        assert_eq!(Race { time: 4, distance: 4 }.winning_holds(), None);
+        assert_eq!(Race { time: 4, distance: 3 }.winning_holds(), Some(2..=2));
+        assert_eq!(Race { time: 10, distance: 21 }.winning_holds(), Some(4..=6));
+        assert_eq!(Race { time: 0, distance: 0 }.winning_holds(), None);
+
+        // the closed form agrees with trying every hold time
+        for time in 0..60 {
+            for distance in 0..1000 {
+                let race = Race { time, distance };
+                let count = (0..=time).filter(|hold| (time - hold) * hold > distance).count() as u128;
+                assert_eq!(race.ways_to_win(), count, "{race:?}");
+                let first = race.first_win_bisected();
+                assert_eq!(race.winning_holds().map(|holds| *holds.start()), race.wins(first).then_some(first));
+            }
+        }
+
+        // races too long to square fall back to bisection
+        assert_eq!(Race { time: 1 << 65, distance: u128::MAX }.winning_holds(), Some(1 << 64..=1 << 64));
+        assert_eq!(Race { time: (1 << 65) - 1, distance: u128::MAX }.winning_holds(), None);
+        assert_eq!(Race { time: u128::MAX, distance: 0 }.ways_to_win(), u128::MAX - 1);
+    }
+
+    #[test]
+    fn models() {
+        // the numeric solver agrees with the exact one
+        for time in 0..40 {
+            for distance in 0..400 {
+                let race = Race { time, distance };
+                assert_eq!(solve(&Linear, &race), race.winning_holds(), "{race:?}");
+            }
+        }
+
+        let race = Race { time: 30, distance: 200 };
+        assert_eq!(Accelerated { factor: 2.0 }.winning_holds(&race), Some(4..=26));
+        assert_eq!(TopSpeed { factor: 1.0, top_speed: 10.0 }.winning_holds(&race), None);
+        assert_eq!(TopSpeed { factor: 1.0, top_speed: 12.0 }.winning_holds(&race), Some(11..=13));
+        assert_eq!(Drag { factor: 1.0, drag: 1e-9 }.winning_holds(&race), Some(11..=19));
+        assert_eq!(Drag { factor: 1.0, drag: 0.1 }.winning_holds(&race), None);
+
+        let races = read_races("Time:      7  15   30
+        Distance:  9  40  200");
+        let table = table(&races, &TopSpeed { factor: 1.0, top_speed: 12.0 });
+        assert_eq!(table.lines().count(), 4);
+        assert!(table.lines().nth(3).unwrap().ends_with("11              13               3"));
+    }
+}
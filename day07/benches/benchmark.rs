@@ -1,13 +1,36 @@
 use std::fs;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
-use day07::both;
+use day07::{both, poker, Ruleset};
 
 
 pub fn criterion_benchmark(c: &mut Criterion) {
     let input = fs::read_to_string("input.txt").unwrap();
-    c.bench_function("Part 1", |b| b.iter(|| both(black_box(&input), 0)));
-    c.bench_function("Part 2", |b| b.iter(|| both(black_box(&input), 1)));
+    c.bench_function("Part 1", |b| b.iter(|| both(black_box(&input), &Ruleset::standard())));
+    c.bench_function("Part 2", |b| b.iter(|| both(black_box(&input), &Ruleset::jokers())));
+
+    // as many poker hands as the input has lines, dealt from a deck shuffled by a fixed linear congruential generator
+    let mut state: u64 = 7;
+    let mut next = || {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (state >> 33) as usize
+    };
+    let poker_input: String = (0..input.lines().count())
+        .map(|_| {
+            let mut deck: Vec<String> = "23456789TJQKA"
+                .chars()
+                .flat_map(|rank| "shdc".chars().map(move |suit| format!("{rank}{suit}")))
+                .collect();
+            let cards: String = (0..5)
+                .map(|_| {
+                    let card = next() % deck.len();
+                    deck.swap_remove(card)
+                })
+                .collect();
+            format!("{cards} {}\n", next() % 1000)
+        })
+        .collect();
+    c.bench_function("Poker", |b| b.iter(|| poker(black_box(&poker_input)).unwrap()));
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -1,7 +1,35 @@
-use std::cmp::Ordering;
+use std::{cmp::Ordering, error::Error, fmt, str::FromStr};
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
-enum HandType {
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseHandError {
+    MissingBid,
+    InvalidBid(String),
+    InvalidCard(char),
+    InvalidSuit(char),
+    DuplicateCard(String),
+    CardCount {
+        found: usize,
+        expected: usize
+    }
+}
+
+impl fmt::Display for ParseHandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseHandError::MissingBid => write!(f, "Error: Missing bid after the cards."),
+            ParseHandError::InvalidBid(bid) => write!(f, "Error: Invalid bid '{bid}'."),
+            ParseHandError::InvalidCard(card) => write!(f, "Error: Not a valid card '{card}'."),
+            ParseHandError::InvalidSuit(suit) => write!(f, "Error: Not a valid suit '{suit}'."),
+            ParseHandError::DuplicateCard(card) => write!(f, "Error: Card '{card}' is dealt twice."),
+            ParseHandError::CardCount { found, expected } => write!(f, "Error: Found {found} cards, expected {expected}.")
+        }
+    }
+}
+
+impl Error for ParseHandError {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HandType {
     Five = 6,
     Four = 5,
     FullHouse = 4,
@@ -11,89 +39,150 @@ enum HandType {
     High = 0
 }
 
-#[derive(Debug, Eq)]
-struct Hand {
-    bid: u32,
-    cards: [u8; 5],
-    hand_type: HandType
+// how hands of the same type are ordered
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TieBreak {
+    // the first card that differs decides
+    InOrder,
+    // the highest card that differs decides, no matter where it is
+    Sorted,
+    // hands of the same type are equal
+    Never
 }
 
-impl Hand {
-    fn from(s: &str, jokers: u8) -> Self {
-        let split: Vec<&str> = s
-            .split_whitespace()
-            .map(|line| line.trim())
-            .collect();
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ruleset {
+    // the card ranks from weakest to strongest
+    pub ranks: Vec<char>,
+    // ranks that count as whichever rank makes the best hand
+    pub wild: Vec<char>,
+    pub hand_size: usize,
+    pub tie_break: TieBreak
+}
 
-        let bid: u32 = split[1].parse().unwrap();
+impl Ruleset {
+    pub fn standard() -> Self {
+        Ruleset {
+            ranks: "23456789TJQKA".chars().collect(),
+            wild: Vec::new(),
+            hand_size: 5,
+            tie_break: TieBreak::InOrder
+        }
+    }
 
-        let mut cards: [u8; 5] = [0; 5];
-        for (i, c) in split[0].chars().enumerate() {
-            cards[i] = match c {
-                'A' => 14 - jokers,
-                'K' => 13 - jokers,
-                'Q' => 12 - jokers,
-                'J' => 11 - 10 * jokers,
-                'T' => 10,
-                c if c.is_numeric() => c.to_digit(10).unwrap() as u8,
-                _ => panic!("Error: Not a valid card.")
+    // jacks are jokers, wild but the weakest card on their own
+    pub fn jokers() -> Self {
+        Ruleset {
+            ranks: "J23456789TQKA".chars().collect(),
+            wild: vec!['J'],
+            ..Ruleset::standard()
+        }
+    }
+
+    fn rank(&self, card: char) -> Result<u8, ParseHandError> {
+        self.ranks
+            .iter()
+            .position(|rank| *rank == card)
+            .map(|rank| rank as u8)
+            .ok_or(ParseHandError::InvalidCard(card))
+    }
+
+    // the type of cards already checked to be ranks of the ruleset
+    fn hand_type(&self, cards: &str) -> HandType {
+        let mut counts: Vec<usize> = vec![0; self.ranks.len()];
+        let mut wild = 0;
+        for card in cards.chars() {
+            if self.wild.contains(&card) {
+                wild += 1;
+            } else if let Ok(rank) = self.rank(card) {
+                counts[rank as usize] += 1;
             }
         }
 
-        let mut counter = [0; 13];
-        for card in cards.iter() {
-            counter[*card as usize + jokers as usize - 2] += 1;
+        // wild cards join the largest group
+        counts.sort_by(|a, b| b.cmp(a));
+        counts[0] += wild;
+
+        match (counts[0], counts.get(1).copied().unwrap_or(0)) {
+            (5.., _) => HandType::Five,
+            (4, _) => HandType::Four,
+            (3, 2..) => HandType::FullHouse,
+            (3, _) => HandType::Three,
+            (2, 2) => HandType::TwoPair,
+            (2, _) => HandType::Pair,
+            _ => HandType::High
         }
+    }
+}
+
+// splits a "<cards> <bid>" line, the same for camel cards and poker
+fn split_bid(line: &str) -> Result<(&str, u32), ParseHandError> {
+    let mut split = line.split_whitespace();
+    let cards = split.next().unwrap_or_default();
+    let bid = split.next().ok_or(ParseHandError::MissingBid)?;
+
+    let bid = bid
+        .parse()
+        .map_err(|_| ParseHandError::InvalidBid(bid.to_string()))?;
 
-        if jokers == 1 {
-            let (max_index, _) = counter
-                .iter()
-                .enumerate()
-                .skip(1)
-                .max_by(|a, b| a.1.cmp(b.1))
-                .unwrap();
-            counter[max_index] += counter[0];
+    Ok((cards, bid))
+}
+
+#[derive(Clone, Debug, Eq)]
+pub struct Hand {
+    pub cards: String,
+    pub bid: u32,
+    pub hand_type: HandType,
+    // the cards in the order the tie break compares them
+    tie_break: Vec<u8>
+}
+
+impl Hand {
+    // reads "<cards> <bid>" under the given rules
+    pub fn parse(s: &str, ruleset: &Ruleset) -> Result<Self, ParseHandError> {
+        let (cards, bid) = split_bid(s)?;
+
+        let found = cards.chars().count();
+        if found != ruleset.hand_size {
+            return Err(ParseHandError::CardCount { found, expected: ruleset.hand_size });
         }
 
-        let hand_type = match counter.iter().skip(jokers as usize).max() {
-            Some(max) => match max {
-                5 => HandType::Five,
-                4 => HandType::Four,
-
-                3 => match counter.iter().skip(jokers as usize).filter(|count| **count != 0).min() {
-                    Some(min) => match min {
-                        2 => HandType::FullHouse,
-                        1 => HandType::Three,
-                        _ => panic!("Error: Too many cards after three of a kind.")
-                    },
-                    None => panic!("Error: Not enough cards after three of a kind."),
-                },
-                2 => match counter.iter().skip(jokers as usize).filter(|count| **count == 2).count() {
-                    2 => HandType::TwoPair,
-                    1 => HandType::Pair,
-                    0 => match jokers {
-                        1 => HandType::Pair,
-                        _ => panic!("Error: Wrong number of cards after pair with jokers.")
-                    }, 
-                    _ => panic!("Error: Wrong number of cards after pair.")
-                },
-                1 => HandType::High,
-                x => panic!("Error: Found {x} cards.")
+        let ranks = cards
+            .chars()
+            .map(|card| ruleset.rank(card))
+            .collect::<Result<Vec<u8>, _>>()?;
+
+        let tie_break = match ruleset.tie_break {
+            TieBreak::InOrder => ranks,
+            TieBreak::Sorted => {
+                let mut sorted = ranks;
+                sorted.sort_by(|a, b| b.cmp(a));
+                sorted
             },
-            None => panic!("Error: Counter is broken."),
+            TieBreak::Never => Vec::new()
         };
 
-        Hand {
+        Ok(Hand {
+            cards: cards.to_string(),
             bid,
-            cards,
-            hand_type,
-        }
+            hand_type: ruleset.hand_type(cards),
+            tie_break
+        })
+    }
+}
+
+// reads a hand under the standard rules
+impl FromStr for Hand {
+    type Err = ParseHandError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Hand::parse(s, &Ruleset::standard())
     }
 }
 
 impl PartialEq for Hand {
     fn eq(&self, other: &Self) -> bool {
-        self.cards == other.cards && self.hand_type == other.hand_type
+        self.cmp(other) == Ordering::Equal
     }
 }
 
@@ -101,13 +190,7 @@ impl Ord for Hand {
     fn cmp(&self, other: &Self) -> Ordering {
         // compare cards
         if self.hand_type == other.hand_type {
-            for i in 0..self.cards.len() {
-                match self.cards[i].cmp(&other.cards[i]) {
-                    Ordering::Equal => (),
-                    ordering => { return ordering; }
-                }
-            }
-            Ordering::Equal
+            self.tie_break.cmp(&other.tie_break)
 
         // compare hand types
         } else {
@@ -122,19 +205,155 @@ impl PartialOrd for Hand {
     }
 }
 
-pub fn both(input: &str, jokers: u8) -> u32 {
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ranked {
+    pub hand: Hand,
+    // 1 for the weakest hand
+    pub rank: u32,
+    pub winnings: u32
+}
+
+// every hand with its rank and winnings, from the weakest to the strongest
+pub fn rank(input: &str, ruleset: &Ruleset) -> Result<Vec<Ranked>, ParseHandError> {
     let mut hands: Vec<Hand> = input
         .lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| Hand::from(line, jokers))
-        .collect();
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| Hand::parse(line, ruleset))
+        .collect::<Result<_, _>>()?;
     
     hands.sort();
 
-    hands
+    Ok(hands
+        .into_iter()
+        .zip(1..)
+        .map(|(hand, rank)| Ranked {
+            winnings: hand.bid * rank,
+            hand,
+            rank
+        })
+        .collect())
+}
+
+pub fn both(input: &str, ruleset: &Ruleset) -> u32 {
+    rank(input, ruleset)
+        .unwrap_or_else(|error| panic!("{error}"))
+        .iter()
+        .map(|ranked| ranked.winnings)
+        .sum()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PokerHandType {
+    HighCard,
+    Pair,
+    TwoPair,
+    Three,
+    Straight,
+    Flush,
+    FullHouse,
+    Four,
+    StraightFlush
+}
+
+// a poker hand like AsKdQhJcTs, hands compare by type and then by their kickers
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PokerHand {
+    pub hand_type: PokerHandType,
+    // the ranks from 2 to 14 by how often they occur and then by rank, straights only keep their top card
+    pub kickers: Vec<u8>
+}
+
+impl FromStr for PokerHand {
+    type Err = ParseHandError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars: Vec<char> = s.chars().collect();
+        if chars.len() != 10 {
+            return Err(ParseHandError::CardCount { found: chars.len().div_ceil(2), expected: 5 });
+        }
+
+        let mut ranks: Vec<u8> = Vec::with_capacity(5);
+        let mut suits: Vec<char> = Vec::with_capacity(5);
+        for (i, card) in chars.chunks(2).enumerate() {
+            ranks.push(match card[0] {
+                'A' => 14,
+                'K' => 13,
+                'Q' => 12,
+                'J' => 11,
+                'T' => 10,
+                c @ '2'..='9' => c.to_digit(10).unwrap() as u8,
+                c => return Err(ParseHandError::InvalidCard(c))
+            });
+            suits.push(match card[1] {
+                's' | 'h' | 'd' | 'c' => card[1],
+                c => return Err(ParseHandError::InvalidSuit(c))
+            });
+
+            // only valid cards can be duplicates
+            if chars[..2 * i].chunks(2).any(|dealt| dealt == card) {
+                return Err(ParseHandError::DuplicateCard(card.iter().collect()));
+            }
+        }
+
+        // group the ranks, larger groups first
+        let mut counts = [0; 15];
+        for rank in ranks.iter() {
+            counts[*rank as usize] += 1;
+        }
+        let mut groups: Vec<(usize, u8)> = (2..15)
+            .rev()
+            .filter(|rank| counts[*rank as usize] > 0)
+            .map(|rank| (counts[rank as usize], rank))
+            .collect();
+        groups.sort_by(|a, b| b.cmp(a));
+
+        let flush = suits.iter().all(|suit| *suit == suits[0]);
+        let straight = match groups[..] {
+            // the ace plays low in a wheel
+            [(1, 14), (1, 5), (1, 4), (1, 3), (1, 2)] => Some(5),
+            [(1, high), _, _, _, (1, low)] if high - low == 4 => Some(high),
+            _ => None
+        };
+
+        let hand_type = match (straight, flush, groups[0].0, groups.get(1).map_or(0, |group| group.0)) {
+            (Some(_), true, _, _) => PokerHandType::StraightFlush,
+            (_, _, 4, _) => PokerHandType::Four,
+            (_, _, 3, 2) => PokerHandType::FullHouse,
+            (_, true, _, _) => PokerHandType::Flush,
+            (Some(_), _, _, _) => PokerHandType::Straight,
+            (_, _, 3, _) => PokerHandType::Three,
+            (_, _, 2, 2) => PokerHandType::TwoPair,
+            (_, _, 2, _) => PokerHandType::Pair,
+            _ => PokerHandType::HighCard
+        };
+
+        Ok(PokerHand {
+            hand_type,
+            kickers: match straight {
+                Some(high) => vec![high],
+                None => groups.iter().map(|(_, rank)| *rank).collect()
+            }
+        })
+    }
+}
+
+// ranks a list of poker hands and bids like both does for camel cards
+pub fn poker(input: &str) -> Result<u32, ParseHandError> {
+    let mut hands: Vec<(PokerHand, u32)> = input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (cards, bid) = split_bid(line)?;
+            Ok((cards.parse()?, bid))
+        })
+        .collect::<Result<_, _>>()?;
+
+    hands.sort();
+
+    Ok(hands
         .iter()
         .enumerate()
-        .fold(0, |acc, (i, hand)| acc + hand.bid * (i as u32 + 1))
+        .fold(0, |acc, (i, (_, bid))| acc + bid * (i as u32 + 1)))
 }
 
 #[cfg(test)]
@@ -143,11 +362,13 @@ mod tests {
 
     #[test]
     fn card() {
-        assert_eq!(Hand::from("JQQQQ 1", 1).hand_type, HandType::Five);
-        assert_eq!(Hand::from("JQ4QQ 1", 1).hand_type, HandType::Four);
-        assert_eq!(Hand::from("JQQ44 1", 1).hand_type, HandType::FullHouse);
-        assert_eq!(Hand::from("JQQ53 1", 1).hand_type, HandType::Three);
-        assert_eq!(Hand::from("J32QK 1", 1).hand_type, HandType::Pair);
+        let jokers = Ruleset::jokers();
+        assert_eq!(Hand::parse("JQQQQ 1", &jokers).unwrap().hand_type, HandType::Five);
+        assert_eq!(Hand::parse("JQ4QQ 1", &jokers).unwrap().hand_type, HandType::Four);
+        assert_eq!(Hand::parse("JQQ44 1", &jokers).unwrap().hand_type, HandType::FullHouse);
+        assert_eq!(Hand::parse("JQQ53 1", &jokers).unwrap().hand_type, HandType::Three);
+        assert_eq!(Hand::parse("J32QK 1", &jokers).unwrap().hand_type, HandType::Pair);
+        assert_eq!(Hand::parse("JJJJJ 1", &jokers).unwrap().hand_type, HandType::Five);
     }
 
     #[test]
@@ -157,7 +378,7 @@ mod tests {
         KK677 28
         KTJJT 220
         QQQJA 483";
-        assert_eq!(both(input, 0), 6440);
+        assert_eq!(both(input, &Ruleset::standard()), 6440);
     }
 
     #[test]
@@ -167,6 +388,113 @@ mod tests {
         KK677 28
         KTJJT 220
         QQQJA 483";
-        assert_eq!(both(input, 1), 5905);
+        assert_eq!(both(input, &Ruleset::jokers()), 5905);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn rulesets() {
+        let input = "32T3K 765
+        T55J5 684
+        KK677 28
+        KTJJT 220
+        QQQJA 483";
+
+        // twos as well as jacks are wild
+        let ruleset = Ruleset {
+            ranks: "2J3456789TQKA".chars().collect(),
+            wild: vec!['2', 'J'],
+            ..Ruleset::standard()
+        };
+        assert_eq!(Hand::parse("2J2J3 1", &ruleset).unwrap().hand_type, HandType::Five);
+        assert_eq!(Hand::parse("32T3K 1", &ruleset).unwrap().hand_type, HandType::Three);
+        assert_eq!(both(input, &ruleset), 765 * 2 + 28 + 684 * 3 + 483 * 4 + 220 * 5);
+
+        // a three card variant
+        let ruleset = Ruleset { hand_size: 3, ..Ruleset::standard() };
+        assert_eq!(Hand::parse("AAA 1", &ruleset).unwrap().hand_type, HandType::Three);
+        // 2KA ranks first, A2K second and QQ2 third
+        assert_eq!(both("A2K 1\nQQ2 2\n2KA 3", &ruleset), 3 + 2 + 2 * 3);
+
+        // sorted tie breaks only care about the highest cards
+        let ruleset = Ruleset { tie_break: TieBreak::Sorted, ..Ruleset::standard() };
+        assert_eq!(Hand::parse("2345A 1", &ruleset).unwrap(), Hand::parse("A5432 2", &ruleset).unwrap());
+        assert!(Hand::parse("2345A 1", &ruleset).unwrap() > Hand::parse("KQJT9 2", &ruleset).unwrap());
+
+        let ruleset = Ruleset { tie_break: TieBreak::Never, ..Ruleset::standard() };
+        assert_eq!(Hand::parse("KK677 1", &ruleset).unwrap(), Hand::parse("22334 2", &ruleset).unwrap());
+        assert!(Hand::parse("KK677 1", &ruleset).unwrap() < Hand::parse("22234 2", &ruleset).unwrap());
+    }
+
+    #[test]
+    fn poker_hands() {
+        let hand = |cards: &str| cards.parse::<PokerHand>().unwrap().hand_type;
+        assert_eq!(hand("AsKsQsJsTs"), PokerHandType::StraightFlush);
+        assert_eq!(hand("9h9d9c9sKd"), PokerHandType::Four);
+        assert_eq!(hand("3h3d3cKsKd"), PokerHandType::FullHouse);
+        assert_eq!(hand("2h7h9hJhKh"), PokerHandType::Flush);
+        assert_eq!(hand("As2d3h4c5s"), PokerHandType::Straight);
+        assert_eq!(hand("QsQdQh4c5s"), PokerHandType::Three);
+        assert_eq!(hand("QsQd4h4c5s"), PokerHandType::TwoPair);
+        assert_eq!(hand("QsQd3h4c5s"), PokerHandType::Pair);
+        assert_eq!(hand("QsJd3h4c5s"), PokerHandType::HighCard);
+        assert_eq!(hand("KsAd2h3c4s"), PokerHandType::HighCard);
+
+        // kickers break ties within a type
+        assert!("As2d3h4c5s".parse::<PokerHand>().unwrap() < "2s3d4h5c6s".parse::<PokerHand>().unwrap());
+        assert!("QsQd4h4c5s".parse::<PokerHand>().unwrap() < "QhQc4s4d6s".parse::<PokerHand>().unwrap());
+        assert!("QsQd4h4c5s".parse::<PokerHand>().unwrap() > "JhJcTsTdAs".parse::<PokerHand>().unwrap());
+        assert!("3h3d3cKsKd".parse::<PokerHand>().unwrap() > "2h2d2cAsAd".parse::<PokerHand>().unwrap());
+        assert_eq!("QsQd4h4c5s".parse::<PokerHand>().unwrap(), "QhQc4s4d5h".parse::<PokerHand>().unwrap());
+
+        let input = "AsKsQsJsTs 1
+        2h7h9hJhKh 10
+        QsQd4h4c5s 100
+        As2d3h4c5s 1000";
+        assert_eq!(poker(input), Ok(100 + 1000 * 2 + 10 * 3 + 4));
+
+        // broken hands are errors, dealing a card twice included
+        assert_eq!("AhAhAhAhAh".parse::<PokerHand>(), Err(ParseHandError::DuplicateCard(String::from("Ah"))));
+        assert_eq!("AsKd".parse::<PokerHand>(), Err(ParseHandError::CardCount { found: 2, expected: 5 }));
+        assert_eq!("AsKdQhJcTx".parse::<PokerHand>(), Err(ParseHandError::InvalidSuit('x')));
+        assert_eq!("AsKdQhJc1s".parse::<PokerHand>(), Err(ParseHandError::InvalidCard('1')));
+        assert_eq!("1x1x1x1x1x".parse::<PokerHand>(), Err(ParseHandError::InvalidCard('1')));
+        assert_eq!(poker("AsKd 5"), Err(ParseHandError::CardCount { found: 2, expected: 5 }));
+        assert_eq!(poker("AsKsQsJsTs"), Err(ParseHandError::MissingBid));
+        assert_eq!(poker("AsKsQsJsTs x"), Err(ParseHandError::InvalidBid(String::from("x"))));
+    }
+
+    #[test]
+    fn ranked() {
+        let input = "32T3K 765
+        T55J5 684
+        KK677 28
+        KTJJT 220
+        QQQJA 483";
+        let ranked = rank(input, &Ruleset::jokers()).unwrap();
+        let report: Vec<(&str, HandType, u32, u32)> = ranked
+            .iter()
+            .map(|ranked| (ranked.hand.cards.as_str(), ranked.hand.hand_type, ranked.rank, ranked.winnings))
+            .collect();
+        assert_eq!(report, vec![
+            ("32T3K", HandType::Pair, 1, 765),
+            ("KK677", HandType::TwoPair, 2, 56),
+            ("T55J5", HandType::Four, 3, 2052),
+            ("QQQJA", HandType::Four, 4, 1932),
+            ("KTJJT", HandType::Four, 5, 1100)
+        ]);
+
+        let hand: Hand = "KK677 28".parse().unwrap();
+        assert_eq!((hand.cards.as_str(), hand.bid, hand.hand_type), ("KK677", 28, HandType::TwoPair));
+
+        assert_eq!("KK677".parse::<Hand>(), Err(ParseHandError::MissingBid));
+        assert_eq!("KK677 lots".parse::<Hand>(), Err(ParseHandError::InvalidBid(String::from("lots"))));
+        assert_eq!("KK67X 1".parse::<Hand>(), Err(ParseHandError::InvalidCard('X')));
+        assert_eq!("KK67 1".parse::<Hand>(), Err(ParseHandError::CardCount { found: 4, expected: 5 }));
+        assert_eq!(rank("KK677 1\nKK6 2", &Ruleset::standard()).unwrap_err().to_string(), "Error: Found 3 cards, expected 5.");
+
+        // poker lines follow the same rules
+        for (line, poker_line) in [("KK677", "KsKd6h7c7s"), ("KK677 lots", "KsKd6h7c7s lots"), ("KK67 1", "KsKd6h7c 1")] {
+            assert_eq!(poker(poker_line).unwrap_err(), line.parse::<Hand>().unwrap_err(), "{line}");
+        }
+    }
+}